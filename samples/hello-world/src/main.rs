@@ -1,33 +1,24 @@
-use std::env;
-use visa_sdk::{
-    api::hello_world::HelloWorld,
-    client::{models::MutualTlsBuilder, VisaClientBuilder},
-};
+use visa_sdk::{api::hello_world::HelloWorld, client::VisaClientBuilder};
 
 #[tokio::main]
 async fn main() -> Result<(), ()> {
     dotenvy::dotenv().unwrap();
-    let user_id = env::var("VISA_USER_ID").unwrap();
-    let password = env::var("VISA_PASSWORD").unwrap();
-    let cert_path = env::var("VISA_CERT").unwrap();
-    let cert_key = env::var("VISA_CERT_PASSWORD").map_or(None, |v| Some(v));
-    let pfx_bytes = std::fs::read(cert_path).unwrap();
 
-    let client = VisaClientBuilder::new()
-        .set_mutual_tls(
-            MutualTlsBuilder::default()
-                .user_id(user_id)
-                .password(password)
-                .cert(pfx_bytes)
-                .cert_key(cert_key)
-                .build()
-                .unwrap(),
-        )
-        .build();
+    // Reads VISA_USER_ID, VISA_PASSWORD, VISA_CERT and VISA_CERT_PASSWORD,
+    // among others, see `VisaClientConfig::from_env`.
+    let client = VisaClientBuilder::from_env()
+        .expect("Invalid configuration")
+        .build()
+        .expect("Failed to build VisaClient");
 
     let hello = HelloWorld::new(client);
 
-    let response = hello.get().await;
-    println!("{:?}", response);
+    match hello.ping().await {
+        Ok(ping) => println!(
+            "{:?} answered in {:?} ({:?}): {:?}",
+            ping.api_level, ping.latency, ping.correlation_id, ping.response
+        ),
+        Err(error) => println!("HelloWorld failed: {error}"),
+    }
     Ok(())
 }
@@ -0,0 +1,239 @@
+pub mod models;
+
+#[double]
+use crate::client::VisaClient;
+use crate::{
+    api::{
+        request::json_request,
+        response::{parse_response, VisaResponse},
+        result::Result,
+    },
+    client::{models::RequestOptions, state::WithMessageLevelEncryption},
+};
+use mockall_double::double;
+use models::*;
+use reqwest::Method;
+use url::Url;
+
+/// [`EnhancedForeignExchange`] struct provides methods to interact with the
+/// Visa Enhanced Foreign Exchange Rates API.
+///
+/// The enhanced API requires Message Level Encryption, so it can only be
+/// created from a [`VisaClient`] in the [`WithMessageLevelEncryption`] state.
+/// Passing a client without MLE is a compile error.
+///
+/// # Example
+///
+/// ```no_run
+/// # use visa_sdk::client::{state::WithMessageLevelEncryption, VisaClient};
+/// use visa_sdk::api::foreign_exchange::EnhancedForeignExchange;
+///
+/// # fn example(client: VisaClient<WithMessageLevelEncryption>) {
+/// let forex = EnhancedForeignExchange::new(client);
+/// # }
+/// ```
+///
+/// Guide:
+/// - <https://developer.visa.com/capabilities/foreign_exchange>
+#[derive(Clone)]
+pub struct EnhancedForeignExchange {
+    client: VisaClient<WithMessageLevelEncryption>,
+    url: Url,
+    options: RequestOptions,
+}
+
+impl EnhancedForeignExchange {
+    const URL: &'static str = "forexrates/v1/enhancedforeignexchangerates";
+
+    /// Creates a new instance of `EnhancedForeignExchange`.
+    ///
+    /// # Arguments
+    ///
+    /// - `client`: A `VisaClient` instance with Message Level Encryption
+    ///   enabled.
+    pub fn new(client: VisaClient<WithMessageLevelEncryption>) -> Self {
+        let url = client.get_base_url().join(Self::URL).unwrap();
+        EnhancedForeignExchange {
+            client,
+            url,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Sets the [`RequestOptions`] of every call. A correlation ID set here is
+    /// shared by all the calls.
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Fetches foreign exchange rates using `EnhancedFXRequest` payload.
+    ///
+    /// # Arguments
+    ///
+    /// - `payload`: An instance of `EnhancedFXRequest`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use visa_sdk::api::foreign_exchange::EnhancedForeignExchange;
+    /// use visa_sdk::api::foreign_exchange::enhanced::models::EnhancedFXRequestBuilder;
+    /// use visa_sdk::api::foreign_exchange::models::RateProduct;
+    ///
+    /// # async fn example(forex: EnhancedForeignExchange) {
+    /// let payload = EnhancedFXRequestBuilder::default()
+    ///     .rate_product_code(RateProduct::Bank)
+    ///     .source_currency_code("USD")
+    ///     .destination_currency_code("GBP")
+    ///     .source_amount(Some("100.55".parse().unwrap()))
+    ///     .initiating_party_id(Some(1002))
+    ///     .quote_id_required(Some(true))
+    ///     .build()
+    ///     .expect("Failed to build EnhancedFXRequest");
+    /// let response = forex.get(payload).await.expect("Failed to get response");
+    /// println!("{:?}", response);
+    /// # }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "enhanced_foreign_exchange.get",
+            skip_all,
+            fields(
+                method = "POST",
+                path = %self.url.path(),
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn get(
+        &self,
+        payload: EnhancedFXRequest,
+    ) -> Result<VisaResponse<EnhancedFXResponse>> {
+        let request = json_request(Method::POST, self.url.clone(), &payload)?;
+        let response = self
+            .client
+            .execute_request_with_options(request, &self.options)
+            .await?;
+        parse_response::<EnhancedFXResponse>(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::foreign_exchange::models::RateProduct;
+    use crate::client::models::ApiLevel;
+    use crate::money::{Amount, Currency, Rate};
+    use http::response::Builder as ResponseBuilder;
+    use serde_json::json;
+
+    const MOCK_URL: &str = "https://domain.test";
+
+    fn setup_mock_client(
+        mock_client: &mut VisaClient<WithMessageLevelEncryption>,
+        url: &str,
+        expected_body: serde_json::Value,
+        body: &str,
+    ) {
+        let response = ResponseBuilder::new()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+
+        let url_clone = url.to_string();
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(move |request, _| {
+                let body: serde_json::Value =
+                    serde_json::from_slice(request.body().unwrap().as_bytes().unwrap()).unwrap();
+                request.method() == Method::POST
+                    && request.url().as_str() == url_clone
+                    && request.headers()["content-type"] == "application/json"
+                    && request.headers()["accept"] == "application/json"
+                    && body == expected_body
+            })
+            .returning(move |_, _| Ok(response.clone().into()));
+        mock_client
+            .expect_get_config()
+            .return_const(crate::client::models::Config {
+                api_level: ApiLevel::Sandbox,
+                ..Default::default()
+            });
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse(MOCK_URL).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_enhanced_foreign_exchange_get() {
+        let mut mock_client = VisaClient::<WithMessageLevelEncryption>::new();
+        setup_mock_client(
+            &mut mock_client,
+            format!(
+                "{}/forexrates/v1/enhancedforeignexchangerates",
+                self::MOCK_URL
+            )
+            .as_str(),
+            json!({
+                "rate_product_code": "BANK",
+                "source_currency_code": "USD",
+                "destination_currency_code": "GBP",
+                "source_amount": "100.55",
+                "markup": { "rate": "0.07", "apply_on": "DESTINATION_AMOUNT" },
+                "initiating_party_id": 1002,
+                "quote_id_required": true
+            }),
+            r#"{
+                "rate_product_code": "BANK",
+                "source_currency_code": "USD",
+                "destination_currency_code": "GBP",
+                "conversion_rate": "0.754",
+                "source_amount": "100.55",
+                "destination_amount": "75.85",
+                "markup_rate_applied": "0.07",
+                "original_destn_amt_before_mark_up": "75.90",
+                "quote_id": 987654321,
+                "quote_id_expiry_datetime": "2024-01-08T10:22:15.529+00:00",
+                "rate_published_datetime": "2024-01-08T10:02:15.529+00:00"
+            }"#,
+        );
+
+        let forex = EnhancedForeignExchange::new(mock_client);
+        let payload = EnhancedFXRequestBuilder::default()
+            .rate_product_code(RateProduct::Bank)
+            .source_currency_code("USD")
+            .destination_currency_code("GBP")
+            .source_amount(Some(Amount::new(10055, 2)))
+            .markup(Some(
+                MarkupBuilder::default()
+                    .rate(Rate::new(7, 2))
+                    .apply_on(Some(MarkupApplication::DestinationAmount))
+                    .build()
+                    .unwrap(),
+            ))
+            .initiating_party_id(Some(1002))
+            .quote_id_required(Some(true))
+            .build()
+            .expect("Failed to build EnhancedFXRequest");
+
+        let result = forex.get(payload).await.expect("Failed to get response");
+
+        assert_eq!(
+            result.body,
+            EnhancedFXResponse {
+                rate_product_code: Some(RateProduct::Bank),
+                source_currency_code: Some(Currency::USD),
+                destination_currency_code: Some(Currency::GBP),
+                conversion_rate: Rate::new(754, 3),
+                source_amount: Some(Amount::new(10055, 2)),
+                destination_amount: Some(Amount::new(7585, 2)),
+                markup_rate_applied: Some(Rate::new(7, 2)),
+                original_destn_amt_before_mark_up: Some(Amount::new(7590, 2)),
+                quote_id: Some(987654321),
+                quote_id_expiry_datetime: Some("2024-01-08T10:22:15.529+00:00".to_string()),
+                rate_published_datetime: Some("2024-01-08T10:02:15.529+00:00".to_string()),
+            }
+        );
+    }
+}
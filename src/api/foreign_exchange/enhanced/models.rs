@@ -0,0 +1,219 @@
+//! # Enhanced Foreign Exchange Rates Models
+//!
+//! This module contains data models used to interact with the Visa Enhanced
+//! Foreign Exchange Rates API. The enhanced variant supports the same rate
+//! products as the regular API (`A`, `B`, `BANK`, and `WALLET`), in a single
+//! request and response format, and returns additional details such as the
+//! quote ID, the applied markup and the time the rate was published.
+//!
+//! All payloads of this API are protected with Message Level Encryption
+//! (MLE). The encryption is handled transparently by
+//! [`VisaClient`][crate::client::VisaClient].
+//!
+//! ### Available Models
+//!
+//! - [`EnhancedFXRequest`]: The rate inquiry, including the rate product code,
+//!   the markup options and whether a quote ID should be locked.
+//! - [`EnhancedFXResponse`]: The conversion rate and amounts, along with the
+//!   quote and markup details.
+//!
+//! ## Additional Information
+//!
+//! For further details, consult the Visa API documentation: [Visa Foreign
+//! Exchange](https://developer.visa.com/capabilities/foreign_exchange).
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::foreign_exchange::models::{AcquirerDetails, RateProduct},
+    money::{Amount, Currency, CurrencyInput, Rate},
+};
+
+/// Enhanced Foreign Exchange Rates API request structure.
+///
+/// For more details, visit the [Visa Foreign Exchange API
+/// Documentation](https://developer.visa.com/capabilities/foreign_exchange/reference).
+#[derive(Clone, Debug, Serialize, Deserialize, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError"))]
+pub struct EnhancedFXRequest {
+    /// The rate product to inquire.
+    ///
+    /// Example: `RateProduct::Bank`
+    pub rate_product_code: RateProduct,
+
+    /// ISO 4217 source currency, serialized as a 3-letter string (e.g.,
+    /// "USD"). Unknown codes are rejected when the request is built.
+    ///
+    /// Example: `"USD"`
+    #[builder(
+        setter(into),
+        field(
+            ty = "CurrencyInput",
+            build = "self.source_currency_code.build(\"source_currency_code\")?"
+        )
+    )]
+    pub source_currency_code: Currency,
+
+    /// ISO 4217 destination currency, serialized as a 3-letter string (e.g.,
+    /// "GBP"). Unknown codes are rejected when the request is built.
+    ///
+    /// Example: `"GBP"`
+    #[builder(
+        setter(into),
+        field(
+            ty = "CurrencyInput",
+            build = "self.destination_currency_code.build(\"destination_currency_code\")?"
+        )
+    )]
+    pub destination_currency_code: Currency,
+
+    /// The amount to convert from the source currency.
+    ///
+    /// Example: `"100.55"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_amount: Option<Amount>,
+
+    /// The amount to receive in the destination currency. Mutually exclusive
+    /// with `source_amount`.
+    ///
+    /// Example: `"85.42"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_amount: Option<Amount>,
+
+    /// Optional markup to apply on top of the Visa rate.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markup: Option<Markup>,
+
+    /// ID assigned by Visa to identify the originating entity. Required for
+    /// rate products `BANK` and `WALLET`.
+    ///
+    /// Example: `1002`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initiating_party_id: Option<i64>,
+
+    /// Specifies whether a quote ID is required in the response, to lock the
+    /// rate for future transactions.
+    ///
+    /// Example: `true`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_id_required: Option<bool>,
+
+    /// A previously issued quote ID to inquire, instead of a new rate.
+    ///
+    /// Example: `987654321`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_id: Option<i64>,
+
+    /// Additional information about the acquiring institution.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acquirer_details: Option<AcquirerDetails>,
+}
+
+/// Markup options of an [`EnhancedFXRequest`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError"))]
+pub struct Markup {
+    /// The FX markup rate to apply, as a percentage (e.g., "0.07" for 0.07%).
+    ///
+    /// Example: `"0.07"`
+    pub rate: Rate,
+
+    /// Which amount the markup is applied on.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apply_on: Option<MarkupApplication>,
+}
+
+/// The amount a [`Markup`] is applied on.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarkupApplication {
+    /// Increase the source amount, keeping the destination amount.
+    SourceAmount,
+    /// Decrease the destination amount, keeping the source amount.
+    DestinationAmount,
+}
+
+/// Enhanced Foreign Exchange Rates API response structure.
+///
+/// Example response:
+/// ```json
+/// {
+///     "rate_product_code": "BANK",
+///     "source_currency_code": "USD",
+///     "destination_currency_code": "GBP",
+///     "conversion_rate": "0.754",
+///     "source_amount": "100.55",
+///     "destination_amount": "75.85",
+///     "markup_rate_applied": "0.07",
+///     "original_destn_amt_before_mark_up": "75.90",
+///     "quote_id": 987654321,
+///     "quote_id_expiry_datetime": "2024-01-08T10:22:15.529+00:00",
+///     "rate_published_datetime": "2024-01-08T10:02:15.529+00:00"
+/// }
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct EnhancedFXResponse {
+    /// The rate product used for the conversion.
+    ///
+    /// Example: `RateProduct::Bank`
+    pub rate_product_code: Option<RateProduct>,
+
+    /// ISO 4217 source currency.
+    ///
+    /// Example: `"USD"`
+    pub source_currency_code: Option<Currency>,
+
+    /// ISO 4217 destination currency.
+    ///
+    /// Example: `"GBP"`
+    pub destination_currency_code: Option<Currency>,
+
+    /// Conversion rate applied to the transaction, excluding any markup.
+    ///
+    /// Example: `"0.754"`
+    pub conversion_rate: Rate,
+
+    /// Source amount in the source currency.
+    ///
+    /// Example: `"100.55"`
+    pub source_amount: Option<Amount>,
+
+    /// Destination amount in the destination currency, after markup.
+    ///
+    /// Example: `"75.85"`
+    pub destination_amount: Option<Amount>,
+
+    /// The FX markup rate applied to the transaction, if any.
+    ///
+    /// Example: `"0.07"`
+    pub markup_rate_applied: Option<Rate>,
+
+    /// The destination amount before any markup was applied.
+    ///
+    /// Example: `"75.90"`
+    pub original_destn_amt_before_mark_up: Option<Amount>,
+
+    /// Unique quote ID locking the rate, if requested.
+    ///
+    /// Example: `987654321`
+    pub quote_id: Option<i64>,
+
+    /// Expiration date and time for the quote ID, in ISO 8601 format.
+    ///
+    /// Example: `"2024-01-08T10:22:15.529+00:00"`
+    pub quote_id_expiry_datetime: Option<String>,
+
+    /// Date and time the rate was published by Visa, in ISO 8601 format.
+    ///
+    /// Example: `"2024-01-08T10:02:15.529+00:00"`
+    pub rate_published_datetime: Option<String>,
+}
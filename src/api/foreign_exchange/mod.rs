@@ -8,8 +8,7 @@
 //! ## Overview
 //!
 //! Visa supports both regular and enhanced versions of the Foreign Exchange
-//! Rate API. Currently, this module implements only the regular version of the
-//! API.
+//! Rate API. This module implements both of them.
 //!
 //! - The regular version does not require the use of Message Level Encryption
 //!   (MLE).
@@ -21,42 +20,53 @@
 //! different rate products, such as card-based, account-based, bank, and wallet
 //! rates.
 //!
+//! The [`EnhancedForeignExchange`] struct is the entry point for the enhanced
+//! version. It can only be created from a
+//! [`VisaClient`][crate::client::VisaClient] with Message Level Encryption
+//! enabled, so the MLE requirement is enforced at compile time.
+//!
 //! ## Example
 //!
 //! Below is an example of how to create a request to fetch foreign exchange
 //! rates using the [`ForeignExchange`] struct:
 //!
 //! ```no_run
-//! use visa_sdk::client::VisaClient;
+//! use visa_sdk::client::{models::MutualTls, VisaClientBuilder};
 //! use visa_sdk::api::foreign_exchange::ForeignExchange;
-//! use visa_sdk::api::foreign_exchange::models::{FXRequestAorBBuilder, FXRequestBankOrWalletBuilder};
+//! use visa_sdk::api::foreign_exchange::models::{FXRequestBuilder, RateProduct};
 //!
 //! #[tokio::main]
 //! async fn main() {
-//!     let client = VisaClient::new(/* ... */);
+//!     # let mtls = MutualTls::default();
+//!     let client = VisaClientBuilder::new()
+//!         .set_mutual_tls(mtls)
+//!         .build()
+//!         .expect("Failed to build VisaClient");
 //!     let forex = ForeignExchange::new(client);
 //!
-//!     // Example for FXRequestAorB
-//!     let payload_a_or_b = FXRequestAorBBuilder::default()
+//!     // Example for rate product A
+//!     let payload_a = FXRequestBuilder::default()
+//!         .rate_product_code(RateProduct::A)
 //!         .source_currency_code("USD")
 //!         .destination_currency_code("GBP")
-//!         .source_amount("100.55")
+//!         .source_amount(Some("100.55".parse().unwrap()))
 //!         .build()
-//!         .expect("Failed to build FXRequestAorB");
-//!     let response_a_or_b = forex.get_a_or_b(payload_a_or_b).await.unwrap();
-//!     println!("{:?}", response_a_or_b);
+//!         .expect("Failed to build FXRequest");
+//!     let response_a = forex.get_a_or_b(payload_a).await.unwrap();
+//!     println!("{:?}", response_a);
 //!
-//!     // Example for FXRequestBankOrWallet
-//!     let payload_bank_or_wallet = FXRequestBankOrWalletBuilder::default()
+//!     // Example for rate product BANK
+//!     let payload_bank = FXRequestBuilder::default()
+//!         .rate_product_code(RateProduct::Bank)
 //!         .source_currency_code("USD")
 //!         .destination_currency_code("GBP")
-//!         .initiating_party_id(1002)
-//!         .source_amount(Some(100.55))
+//!         .initiating_party_id(Some(1002))
+//!         .source_amount(Some("100.55".parse().unwrap()))
 //!         .quote_id_required(Some(true))
 //!         .build()
-//!         .expect("Failed to build FXRequestBankOrWallet");
-//!     let response_bank_or_wallet = forex.get_bank_or_wallet(payload_bank_or_wallet).await.unwrap();
-//!     println!("{:?}", response_bank_or_wallet);
+//!         .expect("Failed to build FXRequest");
+//!     let response_bank = forex.get_bank_or_wallet(payload_bank).await.unwrap();
+//!     println!("{:?}", response_bank);
 //! }
 //! ```
 //!
@@ -70,4 +80,7 @@
 
 mod regular;
 
+pub mod enhanced;
+
+pub use enhanced::EnhancedForeignExchange;
 pub use regular::*;
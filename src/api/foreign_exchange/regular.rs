@@ -2,11 +2,17 @@ pub mod models;
 
 #[double]
 use crate::client::VisaClient;
-use crate::{api::result::Result, client::utils::MLETrait};
+use crate::{
+    api::{
+        request::json_request,
+        response::{parse_response, VisaResponse},
+        result::{Error, Result},
+    },
+    client::{models::RequestOptions, utils::MLETrait},
+};
 use mockall_double::double;
 use models::*;
-use reqwest::{Method, Request};
-use serde_json::json;
+use reqwest::Method;
 use url::Url;
 
 /// [`ForeignExchange`] struct provides methods to interact with the Visa
@@ -18,9 +24,13 @@ use url::Url;
 ///
 /// # Example
 ///
-/// ```
-/// let client = VisaClient::new(api_key, secret_key);
+/// ```no_run
+/// # use visa_sdk::client::{state::WithoutMessageLevelEncryption, VisaClient};
+/// use visa_sdk::api::foreign_exchange::ForeignExchange;
+///
+/// # fn example(client: VisaClient<WithoutMessageLevelEncryption>) {
 /// let forex = ForeignExchange::new(client);
+/// # }
 /// ```
 ///
 /// Guide:
@@ -32,13 +42,14 @@ where
 {
     client: VisaClient<MLE>,
     url: Url,
+    options: RequestOptions,
 }
 
 impl<MLE> ForeignExchange<MLE>
 where
     MLE: MLETrait,
 {
-    const URL: &'static str = "/forexrates/v2/foreignexchangerates";
+    const URL: &'static str = "forexrates/v2/foreignexchangerates";
 
     /// Creates a new instance of `ForeignExchange`.
     ///
@@ -46,81 +57,141 @@ where
     ///
     /// - `client`: A `VisaClient` instance. Does not need to have Message Level
     ///   Encryption enabled.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// let client = VisaClient::new(api_key, secret_key);
-    /// let forex = ForeignExchange::new(client);
-    /// ```
     pub fn new(client: VisaClient<MLE>) -> Self {
         let url = client.get_base_url().join(Self::URL).unwrap();
-        ForeignExchange { client, url }
+        ForeignExchange {
+            client,
+            url,
+            options: RequestOptions::default(),
+        }
     }
 
-    /// Fetches foreign exchange rates using `FXRequestAorB` payload.
+    /// Sets the [`RequestOptions`] of every call. A correlation ID set here is
+    /// shared by all the calls.
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Fetches foreign exchange rates using a `FXRequest` payload with rate
+    /// product `A` or `B`.
     ///
     /// This function returns indicative daily rates for transactions with card
     /// or bank-account as the payment instrument.
     ///
     /// # Arguments
     ///
-    /// - `payload`: An instance of `FXRequestAorB`.
+    /// - `payload`: An instance of `FXRequest`, with [`RateProduct::A`] or
+    ///   [`RateProduct::B`].
     ///
     /// # Example
     ///
     /// ```no_run
-    /// let payload = FXRequestAorBBuilder::default()
+    /// # use visa_sdk::api::foreign_exchange::ForeignExchange;
+    /// # use visa_sdk::client::state::WithoutMessageLevelEncryption;
+    /// use visa_sdk::api::foreign_exchange::models::{FXRequestBuilder, RateProduct};
+    ///
+    /// # async fn example(forex: ForeignExchange<WithoutMessageLevelEncryption>) {
+    /// let payload = FXRequestBuilder::default()
+    ///     .rate_product_code(RateProduct::A)
     ///     .source_currency_code("USD")
     ///     .destination_currency_code("GBP")
-    ///     .source_amount("100.55")
+    ///     .source_amount(Some("100.55".parse().unwrap()))
     ///     .build()
-    ///     .expect("Failed to build FXRequestAorB");
+    ///     .expect("Failed to build FXRequest");
     /// let response = forex.get_a_or_b(payload).await.expect("Failed to get response");
     /// println!("{:?}", response);
+    /// # }
     /// ```
-    pub async fn get_a_or_b(&self, payload: FXRequestAorB) -> Result<FXResponseAorB> {
-        let mut request = Request::new(Method::GET, self.url.clone());
-        request
-            .body_mut()
-            .replace(json!(payload).to_string().into());
-        let response = self.client.execute_request(request).await?;
-        Ok(response.json::<FXResponseAorB>().await?)
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "foreign_exchange.get_a_or_b",
+            skip_all,
+            fields(
+                method = "POST",
+                path = %self.url.path(),
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn get_a_or_b(&self, payload: FXRequest) -> Result<VisaResponse<FXResponseAorB>> {
+        if !payload.rate_product_code.is_indicative() {
+            return Err(Error::custom(format!(
+                "get_a_or_b does not support rate product {}",
+                payload.rate_product_code
+            )));
+        }
+
+        let request = json_request(Method::POST, self.url.clone(), &payload)?;
+        let response = self
+            .client
+            .execute_request_with_options(request, &self.options)
+            .await?;
+        parse_response::<FXResponseAorB>(response).await
     }
 
-    /// Fetches foreign exchange rates using `FXRequestBankOrWallet` payload.
+    /// Fetches foreign exchange rates using a `FXRequest` payload with rate
+    /// product `BANK` or `WALLET`.
     ///
     /// This function returns real-time rates for transactions with bank-account
     /// or wallet as the payment instrument.
     ///
     /// # Arguments
     ///
-    /// - `payload`: An instance of `FXRequestBankOrWallet`.
+    /// - `payload`: An instance of `FXRequest`, with [`RateProduct::Bank`] or
+    ///   [`RateProduct::Wallet`].
     ///
     /// # Example
     ///
     /// ```no_run
-    /// let payload = FXRequestBankOrWalletBuilder::default()
+    /// # use visa_sdk::api::foreign_exchange::ForeignExchange;
+    /// # use visa_sdk::client::state::WithoutMessageLevelEncryption;
+    /// use visa_sdk::api::foreign_exchange::models::{FXRequestBuilder, RateProduct};
+    ///
+    /// # async fn example(forex: ForeignExchange<WithoutMessageLevelEncryption>) {
+    /// let payload = FXRequestBuilder::default()
+    ///     .rate_product_code(RateProduct::Bank)
     ///     .source_currency_code("USD")
     ///     .destination_currency_code("GBP")
-    ///     .initiating_party_id(1002)
-    ///     .source_amount(Some(100.55))
+    ///     .initiating_party_id(Some(1002))
+    ///     .source_amount(Some("100.55".parse().unwrap()))
     ///     .quote_id_required(Some(true))
     ///     .build()
-    ///     .expect("Failed to build FXRequestBankOrWallet");
+    ///     .expect("Failed to build FXRequest");
     /// let response = forex.get_bank_or_wallet(payload).await.expect("Failed to get response");
     /// println!("{:?}", response);
+    /// # }
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "foreign_exchange.get_bank_or_wallet",
+            skip_all,
+            fields(
+                method = "POST",
+                path = %self.url.path(),
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
     pub async fn get_bank_or_wallet(
         &self,
-        payload: FXRequestBankOrWallet,
-    ) -> Result<FXResponseBankOrWallet> {
-        let mut request = Request::new(Method::GET, self.url.clone());
-        request
-            .body_mut()
-            .replace(json!(payload).to_string().into());
-        let response = self.client.execute_request(request).await?;
-        Ok(response.json::<FXResponseBankOrWallet>().await?)
+        payload: FXRequest,
+    ) -> Result<VisaResponse<FXResponseBankOrWallet>> {
+        if !payload.rate_product_code.is_real_time() {
+            return Err(Error::custom(format!(
+                "get_bank_or_wallet does not support rate product {}",
+                payload.rate_product_code
+            )));
+        }
+
+        let request = json_request(Method::POST, self.url.clone(), &payload)?;
+        let response = self
+            .client
+            .execute_request_with_options(request, &self.options)
+            .await?;
+        parse_response::<FXResponseBankOrWallet>(response).await
     }
 }
 
@@ -128,13 +199,16 @@ where
 mod tests {
     use super::*;
     use crate::client::models::ApiLevel;
+    use crate::money::{Amount, Rate};
     use http::response::Builder as ResponseBuilder;
+    use serde_json::json;
 
     const MOCK_URL: &str = "https://domain.test";
 
     fn setup_mock_execute_request(
         mock_client: &mut VisaClient<()>,
         url: &str,
+        expected_body: serde_json::Value,
         status: u16,
         body: &str,
     ) {
@@ -145,9 +219,20 @@ mod tests {
 
         let url_clone = url.to_string();
         mock_client
-            .expect_execute_request()
-            .withf(move |request| request.url().as_str() == url_clone)
-            .returning(move |_| Ok(response.clone().into()));
+            .expect_execute_request_with_options()
+            .withf(move |request, _| {
+                let headers = request.headers();
+                let body = request
+                    .body()
+                    .and_then(|body| body.as_bytes())
+                    .and_then(|body| serde_json::from_slice::<serde_json::Value>(body).ok());
+                request.method() == Method::POST
+                    && request.url().as_str() == url_clone
+                    && headers["content-type"] == "application/json"
+                    && headers["accept"] == "application/json"
+                    && body.as_ref() == Some(&expected_body)
+            })
+            .returning(move |_, _| Ok(response.clone().into()));
     }
 
     fn setup_mock_get_config(mock_client: &mut VisaClient<()>, api_level: ApiLevel) {
@@ -167,6 +252,12 @@ mod tests {
         setup_mock_execute_request(
             &mut mock_client,
             format!("{}/forexrates/v2/foreignexchangerates", self::MOCK_URL).as_str(),
+            json!({
+                "rate_product_code": "A",
+                "source_currency_code": "USD",
+                "destination_currency_code": "GBP",
+                "source_amount": "100.55"
+            }),
             200,
             r#"{
                 "conversion_rate": "0.07",
@@ -178,12 +269,13 @@ mod tests {
         setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
 
         let forex = ForeignExchange::new(mock_client);
-        let payload = FXRequestAorBBuilder::default()
-            .source_currency_code("USD".to_string())
-            .destination_currency_code("GBP".to_string())
-            .source_amount("100.55".to_string())
+        let payload = FXRequestBuilder::default()
+            .rate_product_code(RateProduct::A)
+            .source_currency_code("USD")
+            .destination_currency_code("GBP")
+            .source_amount(Some(Amount::new(10055, 2)))
             .build()
-            .expect("Failed to build FXRequestAorB");
+            .expect("Failed to build FXRequest");
 
         let result = forex
             .get_a_or_b(payload)
@@ -191,12 +283,12 @@ mod tests {
             .expect("Failed to get response");
 
         assert_eq!(
-            result,
+            result.body,
             FXResponseAorB {
-                conversion_rate: "0.07".to_string(),
-                destination_amount: "75.85".to_string(),
-                markup_rate_applied: Some("0.07".to_string()),
-                original_destn_amt_before_mark_up: Some("81.16".to_string()),
+                conversion_rate: Rate::new(7, 2),
+                destination_amount: Amount::new(7585, 2),
+                markup_rate_applied: Some(Rate::new(7, 2)),
+                original_destn_amt_before_mark_up: Some(Amount::new(8116, 2)),
             }
         );
     }
@@ -207,6 +299,14 @@ mod tests {
         setup_mock_execute_request(
             &mut mock_client,
             format!("{}/forexrates/v2/foreignexchangerates", self::MOCK_URL).as_str(),
+            json!({
+                "rate_product_code": "BANK",
+                "source_currency_code": "USD",
+                "destination_currency_code": "GBP",
+                "source_amount": "100.55",
+                "initiating_party_id": 1002,
+                "quote_id_required": true
+            }),
             200,
             r#"{
                 "conversion_rate": 0.07,
@@ -219,14 +319,15 @@ mod tests {
         setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
 
         let forex = ForeignExchange::new(mock_client);
-        let payload = FXRequestBankOrWalletBuilder::default()
-            .source_currency_code("USD".to_string())
-            .destination_currency_code("GBP".to_string())
-            .initiating_party_id(1002)
-            .source_amount(Some(100.55))
+        let payload = FXRequestBuilder::default()
+            .rate_product_code(RateProduct::Bank)
+            .source_currency_code("USD")
+            .destination_currency_code("GBP")
+            .initiating_party_id(Some(1002))
+            .source_amount(Some(Amount::new(10055, 2)))
             .quote_id_required(Some(true))
             .build()
-            .expect("Failed to build FXRequestBankOrWallet");
+            .expect("Failed to build FXRequest");
 
         let result = forex
             .get_bank_or_wallet(payload)
@@ -234,14 +335,203 @@ mod tests {
             .expect("Failed to get response");
 
         assert_eq!(
-            result,
+            result.body,
             FXResponseBankOrWallet {
-                conversion_rate: 0.07,
-                source_amount: Some(100.55),
-                destination_amount: Some(75.85),
+                conversion_rate: Rate::new(7, 2),
+                source_amount: Some(Amount::new(10055, 2)),
+                destination_amount: Some(Amount::new(7585, 2)),
                 quote_id: Some(987654321),
                 quote_id_expiry_datetime: Some("2024-01-08T10:22:15.529+00:00".to_string()),
             }
         );
     }
+
+    #[tokio::test]
+    async fn test_foreign_exchange_maps_visa_error() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(
+            &mut mock_client,
+            format!("{}/forexrates/v2/foreignexchangerates", self::MOCK_URL).as_str(),
+            json!({
+                "rate_product_code": "A",
+                "source_currency_code": "USD",
+                "destination_currency_code": "GBP",
+                "source_amount": "100.55"
+            }),
+            400,
+            r#"{
+                "responseStatus": {
+                    "status": 400,
+                    "code": "9125",
+                    "severity": "ERROR",
+                    "message": "Expected input credential was not present",
+                    "info": ""
+                }
+            }"#,
+        );
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let forex = ForeignExchange::new(mock_client);
+        let payload = FXRequestBuilder::default()
+            .rate_product_code(RateProduct::A)
+            .source_currency_code("USD")
+            .destination_currency_code("GBP")
+            .source_amount(Some(Amount::new(10055, 2)))
+            .build()
+            .expect("Failed to build FXRequest");
+
+        let Err(Error::VisaApiError(error)) = forex.get_a_or_b(payload).await else {
+            panic!("expected a VisaApiError");
+        };
+        assert_eq!(error.status, 400);
+        assert_eq!(error.code(), Some("9125"));
+    }
+
+    #[tokio::test]
+    async fn test_foreign_exchange_rejects_mismatched_rate_product() {
+        let mut mock_client = VisaClient::<()>::new();
+        mock_client.expect_execute_request_with_options().never();
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let forex = ForeignExchange::new(mock_client);
+        let payload = FXRequestBuilder::default()
+            .rate_product_code(RateProduct::Wallet)
+            .source_currency_code("USD")
+            .destination_currency_code("GBP")
+            .initiating_party_id(Some(1002))
+            .destination_amount(Some(Amount::new(8542, 2)))
+            .build()
+            .expect("Failed to build FXRequest");
+
+        assert!(matches!(
+            forex.get_a_or_b(payload).await,
+            Err(Error::Custom(_))
+        ));
+    }
+
+    #[test]
+    fn test_fx_request_serializes_rate_product_fields() {
+        let payload = FXRequestBuilder::default()
+            .rate_product_code(RateProduct::Wallet)
+            .source_currency_code("USD")
+            .destination_currency_code("GBP")
+            .initiating_party_id(Some(1002))
+            .destination_amount(Some(Amount::new(8542, 2)))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            json!(payload),
+            json!({
+                "rate_product_code": "WALLET",
+                "source_currency_code": "USD",
+                "destination_currency_code": "GBP",
+                "destination_amount": "85.42",
+                "initiating_party_id": 1002
+            })
+        );
+    }
+
+    #[test]
+    fn test_fx_request_serializes_acquirer_currency_as_numeric() {
+        let payload = FXRequestBuilder::default()
+            .rate_product_code(RateProduct::A)
+            .source_currency_code(crate::money::Currency::USD)
+            .destination_currency_code("826")
+            .source_amount(Some(Amount::new(10055, 2)))
+            .acquirer_details(Some(
+                AcquirerDetailsBuilder::default()
+                    .bin(408999)
+                    .settlement_currency_code("USD")
+                    .build()
+                    .unwrap(),
+            ))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            json!(payload),
+            json!({
+                "rate_product_code": "A",
+                "source_currency_code": "USD",
+                "destination_currency_code": "GBP",
+                "source_amount": "100.55",
+                "acquirer_details": {
+                    "bin": 408999,
+                    "settlement_currency_code": "840"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_fx_request_rejects_invalid_combinations() {
+        let both_amounts = FXRequestBuilder::default()
+            .rate_product_code(RateProduct::Bank)
+            .source_currency_code("USD")
+            .destination_currency_code("GBP")
+            .initiating_party_id(Some(1002))
+            .source_amount(Some(Amount::new(10055, 2)))
+            .destination_amount(Some(Amount::new(8542, 2)))
+            .build();
+        assert!(matches!(
+            both_amounts,
+            Err(crate::utils::BuilderError::ValidationViolition(_))
+        ));
+
+        let missing_party = FXRequestBuilder::default()
+            .rate_product_code(RateProduct::Wallet)
+            .source_currency_code("USD")
+            .destination_currency_code("GBP")
+            .source_amount(Some(Amount::new(10055, 2)))
+            .build();
+        assert!(matches!(
+            missing_party,
+            Err(crate::utils::BuilderError::ValidationViolition(_))
+        ));
+
+        let quote_on_indicative = FXRequestBuilder::default()
+            .rate_product_code(RateProduct::B)
+            .source_currency_code("USD")
+            .destination_currency_code("GBP")
+            .source_amount(Some(Amount::new(10055, 2)))
+            .quote_id_required(Some(true))
+            .build();
+        assert!(matches!(
+            quote_on_indicative,
+            Err(crate::utils::BuilderError::ValidationViolition(_))
+        ));
+
+        let unknown_currency = FXRequestBuilder::default()
+            .rate_product_code(RateProduct::A)
+            .source_currency_code("USX")
+            .destination_currency_code("GBP")
+            .source_amount(Some(Amount::new(10055, 2)))
+            .build();
+        assert!(matches!(
+            unknown_currency,
+            Err(crate::utils::BuilderError::ValidationViolition(_))
+        ));
+
+        let too_precise = FXRequestBuilder::default()
+            .rate_product_code(RateProduct::A)
+            .source_currency_code("JPY")
+            .destination_currency_code("GBP")
+            .source_amount(Some(Amount::new(10055, 2)))
+            .build();
+        assert!(matches!(
+            too_precise,
+            Err(crate::utils::BuilderError::ValidationViolition(_))
+        ));
+
+        let missing_amount = FXRequestBuilder::default()
+            .rate_product_code(RateProduct::A)
+            .source_currency_code("USD")
+            .destination_currency_code("GBP")
+            .build();
+        assert!(matches!(
+            missing_amount,
+            Err(crate::utils::BuilderError::ValidationViolition(_))
+        ));
+    }
 }
@@ -10,20 +10,21 @@
 //!
 //! The module is structured around two main sets of models:
 //!
-//! - **Requests**: Structures that define the input parameters for querying
-//!   exchange rates, tailored for different rate products (`A`, `B`, `BANK`,
-//!   and `WALLET`).
+//! - **Requests**: A single structure that defines the input parameters for
+//!   querying exchange rates. The [`RateProduct`] (`A`, `B`, `BANK`, and
+//!   `WALLET`) drives which fields are allowed.
 //! - **Responses**: Structures that capture the expected response formats based
 //!   on the type of rate product queried.
 //!
 //! ### Available Models
 //!
 //! #### Request Models
-//! - [`FXRequestAorB`]: For rate products `A` and `B`, representing card- and
-//!   account-based rates with options for markup and acquirer details.
-//! - [`FXRequestBankOrWallet`]: For rate products `BANK` and `WALLET`,
-//!   supporting real-time or quote-based exchange rates for account and wallet
-//!   transactions, respectively.
+//! - [`FXRequest`]: For all rate products. Rate products `A` and `B` represent
+//!   card- and account-based rates with options for markup and acquirer
+//!   details. Rate products `BANK` and `WALLET` support real-time or
+//!   quote-based exchange rates for account and wallet transactions,
+//!   respectively.
+//! - [`RateProduct`]: The rate product code of the request.
 //!
 //! #### Response Models
 //! - [`FXResponseAorB`]: Represents the exchange rate and resulting amounts
@@ -42,33 +43,34 @@
 //! ### Example: Building a Request for Rate Product `BANK`
 //!
 //! ```rust
-//! use your_module::FXRequestBankOrWalletBuilder;
+//! use visa_sdk::api::foreign_exchange::models::{FXRequestBuilder, RateProduct};
 //!
-//! let request = FXRequestBankOrWalletBuilder::default()
-//!     .source_currency_code("USD".to_string())
-//!     .destination_currency_code("GBP".to_string())
-//!     .initiating_party_id(1002)
-//!     .source_amount(Some(100.55))
+//! let request = FXRequestBuilder::default()
+//!     .rate_product_code(RateProduct::Bank)
+//!     .source_currency_code("USD")
+//!     .destination_currency_code("GBP")
+//!     .initiating_party_id(Some(1002))
+//!     .source_amount(Some("100.55".parse().unwrap()))
 //!     .quote_id_required(Some(true))
 //!     .build()
-//!     .expect("Failed to build FXRequestBankOrWallet");
+//!     .expect("Failed to build FXRequest");
 //! ```
 //!
 //! The example above demonstrates creating a `BANK` rate request using
-//! [`FXRequestBankOrWallet`]. Using `Builder` ensures that all required fields
-//! are set, while optional fields can be added as needed.
+//! [`FXRequest`]. The builder ensures that all required fields of the rate
+//! product are set, and rejects the fields that do not belong to it.
 //!
 //! ## General Explanation
 //!
-//! - **[`FXRequestAorB`]**: Used for queries involving Visa's daily indicative
-//!   rates. This request is appropriate for card-based (`A`) and account-based
-//!   (`B`) rates, providing options for setting a markup rate and acquirer
-//!   details if required.
+//! - **[`FXRequest`] with `A` or `B`**: Used for queries involving Visa's daily
+//!   indicative rates. This request is appropriate for card-based (`A`) and
+//!   account-based (`B`) rates, providing options for setting a markup rate and
+//!   acquirer details if required. The source amount is required.
 //!
-//! - **[`FXRequestBankOrWallet`]**: Used for obtaining real-time or quoted
-//!   rates for `BANK` and `WALLET` products. This request allows specifying
-//!   either a source or destination amount, as well as an initiating party ID
-//!   and optional quote ID requirement.
+//! - **[`FXRequest`] with `BANK` or `WALLET`**: Used for obtaining real-time or
+//!   quoted rates. This request requires either a source or a destination
+//!   amount (not both), an initiating party ID, and an optional quote ID
+//!   requirement.
 //!
 //! - **[`FXResponseAorB`]**: Captures the exchange rate, destination amount,
 //!   and optional markup rate details for requests using rate products `A` or
@@ -84,106 +86,216 @@
 //! Exchange](https://developer.visa.com/capabilities/foreign_exchange).
 //!
 
+use crate::{
+    money::{Amount, Currency, CurrencyInput, Rate},
+    utils::{BuilderError, BuilderResult},
+};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
-/// Foreign Exchange Rates API request structure for rate product codes A or B.
+/// The rate product code of a Foreign Exchange Rates API request.
+///
+/// See the [Visa Foreign Exchange API
+/// Documentation](https://developer.visa.com/capabilities/foreign_exchange/reference)
+/// for the availability of each product.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum RateProduct {
+    /// Card-based daily indicative rate.
+    A,
+    /// Account-based daily indicative rate.
+    B,
+    /// Real-time rate for transactions with bank-account as the payment
+    /// instrument.
+    #[serde(rename = "BANK")]
+    Bank,
+    /// Real-time rate for transactions with wallet as the payment instrument.
+    #[serde(rename = "WALLET")]
+    Wallet,
+}
+
+impl RateProduct {
+    /// Returns `true` for the daily indicative rate products, `A` and `B`.
+    pub fn is_indicative(&self) -> bool {
+        matches!(self, RateProduct::A | RateProduct::B)
+    }
+
+    /// Returns `true` for the real-time rate products, `BANK` and `WALLET`.
+    pub fn is_real_time(&self) -> bool {
+        matches!(self, RateProduct::Bank | RateProduct::Wallet)
+    }
+}
+
+impl core::fmt::Display for RateProduct {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt.write_str(match self {
+            RateProduct::A => "A",
+            RateProduct::B => "B",
+            RateProduct::Bank => "BANK",
+            RateProduct::Wallet => "WALLET",
+        })
+    }
+}
+
+/// Foreign Exchange Rates API request structure.
 ///
 /// This request structure is used to inquire about Visa's currency conversion
-/// rates for a given currency pair using card-based (`A`) or account-based
-/// (`B`) rates.
+/// rates for a given currency pair. The [`RateProduct`] decides which fields
+/// are serialized, and the builder rejects invalid combinations:
+///
+/// - `A` and `B` require `source_amount`, and may carry `markup_rate` and
+///   `acquirer_details`.
+/// - `BANK` and `WALLET` require `initiating_party_id` and exactly one of
+///   `source_amount` or `destination_amount`, and may carry
+///   `quote_id_required`.
 ///
 /// For more details, visit the [Visa Foreign Exchange API
 /// Documentation](https://developer.visa.com/capabilities/foreign_exchange/reference).
 #[derive(Clone, Debug, Serialize, Deserialize, Builder)]
-#[builder(build_fn(error = "crate::utils::BuilderError"))]
-pub struct FXRequestAorB {
-    /// ISO 4217 code of the source currency, as a 3-letter string (e.g.,
-    /// "USD").
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+pub struct FXRequest {
+    /// The rate product to inquire.
+    ///
+    /// Example: `RateProduct::A`
+    pub rate_product_code: RateProduct,
+
+    /// ISO 4217 source currency, serialized as a 3-letter string (e.g.,
+    /// "USD"). Unknown codes are rejected when the request is built.
     ///
     /// Example: `"USD"`
-    #[builder(setter(into))]
-    pub source_currency_code: String,
+    #[builder(
+        setter(into),
+        field(
+            ty = "CurrencyInput",
+            build = "self.source_currency_code.build(\"source_currency_code\")?"
+        )
+    )]
+    pub source_currency_code: Currency,
 
-    /// ISO 4217 code of the destination currency, as a 3-letter string (e.g.,
-    /// "GBP").
+    /// ISO 4217 destination currency, serialized as a 3-letter string (e.g.,
+    /// "GBP"). Unknown codes are rejected when the request is built.
     ///
     /// Example: `"GBP"`
-    #[builder(setter(into))]
-    pub destination_currency_code: String,
+    #[builder(
+        setter(into),
+        field(
+            ty = "CurrencyInput",
+            build = "self.destination_currency_code.build(\"destination_currency_code\")?"
+        )
+    )]
+    pub destination_currency_code: Currency,
 
-    /// The amount to convert from the source currency. This amount includes any
-    /// markup.
+    /// The amount to convert from the source currency. For rate products `A`
+    /// and `B`, this amount includes any markup.
     ///
     /// Format: A decimal with up to 2 digits after the decimal point. Example:
     /// `"100.55"`
-    #[builder(setter(into))]
-    pub source_amount: String,
-
-    /// Optional FX markup rate to apply. Represents a percentage markup (e.g.,
-    /// "0.07" for 0.07%).
-    ///
-    /// Example: `"0.07"`
     #[builder(default)]
-    pub markup_rate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_amount: Option<Amount>,
 
-    /// Additional information about the acquiring institution.
-    #[builder(default)]
-    pub acquirer_details: Option<AcquirerDetails>,
-}
-
-/// Foreign Exchange Rates API request structure for rate product codes BANK or
-/// WALLET.
-///
-/// This request structure is used for real-time or quote-based rates, such as
-/// account-based (`BANK`) or wallet-based (`WALLET`) rates.
-///
-/// For further details, consult the [Visa Foreign Exchange API
-/// Documentation](https://developer.visa.com/capabilities/foreign_exchange/reference).
-#[derive(Clone, Debug, Serialize, Deserialize, Builder)]
-#[builder(build_fn(error = "crate::utils::BuilderError"))]
-pub struct FXRequestBankOrWallet {
-    /// ISO 4217 code of the source currency, as a 3-letter string (e.g.,
-    /// "USD").
-    ///
-    /// Example: `"USD"`
-    #[builder(setter(into))]
-    pub source_currency_code: String,
-
-    /// ISO 4217 code of the destination currency, as a 3-letter string (e.g.,
-    /// "GBP").
-    ///
-    /// Example: `"GBP"`
-    #[builder(setter(into))]
-    pub destination_currency_code: String,
-
-    /// The source amount in the source currency, if known.
+    /// The destination amount in the destination currency, if known. Only for
+    /// rate products `BANK` and `WALLET`.
     ///
     /// Format: Decimal with up to 2 digits after the decimal point. Example:
-    /// `100.55`
+    /// `"85.42"`
     #[builder(default)]
-    pub source_amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_amount: Option<Amount>,
 
-    /// The destination amount in the destination currency, if known.
+    /// Optional FX markup rate to apply. Represents a percentage markup (e.g.,
+    /// "0.07" for 0.07%). Only for rate products `A` and `B`.
     ///
-    /// Format: Decimal with up to 2 digits after the decimal point. Example:
-    /// `85.42`
+    /// Example: `"0.07"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markup_rate: Option<Rate>,
+
+    /// Additional information about the acquiring institution. Only for rate
+    /// products `A` and `B`.
     #[builder(default)]
-    pub destination_amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acquirer_details: Option<AcquirerDetails>,
 
-    /// ID assigned by Visa to identify the originating entity.
+    /// ID assigned by Visa to identify the originating entity. Required for
+    /// rate products `BANK` and `WALLET`.
     ///
     /// Example: `1002`
-    pub initiating_party_id: i64,
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initiating_party_id: Option<i64>,
 
     /// Specifies whether a quote ID is required in the response for use in
-    /// future transactions.
+    /// future transactions. Only for rate products `BANK` and `WALLET`.
     ///
     /// Example: `true`
     #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub quote_id_required: Option<bool>,
 }
 
+impl FXRequestBuilder {
+    fn validate(&self) -> BuilderResult<()> {
+        let Some(rate_product) = self.rate_product_code else {
+            // Reported as an uninitialized field by the builder.
+            return Ok(());
+        };
+        let source_amount = matches!(self.source_amount, Some(Some(_)));
+        let destination_amount = matches!(self.destination_amount, Some(Some(_)));
+
+        let violation = |message: &str| {
+            Err(BuilderError::ValidationViolition(format!(
+                "rate product {rate_product}: {message}"
+            )))
+        };
+
+        if rate_product.is_indicative() {
+            if !source_amount {
+                return violation("source_amount is required");
+            }
+            if destination_amount {
+                return violation("destination_amount is not supported");
+            }
+            if matches!(self.initiating_party_id, Some(Some(_))) {
+                return violation("initiating_party_id is not supported");
+            }
+            if matches!(self.quote_id_required, Some(Some(_))) {
+                return violation("quote_id_required is not supported");
+            }
+        } else {
+            if source_amount == destination_amount {
+                return violation("exactly one of source_amount or destination_amount is required");
+            }
+            if !matches!(self.initiating_party_id, Some(Some(_))) {
+                return violation("initiating_party_id is required");
+            }
+            if matches!(self.markup_rate, Some(Some(_))) {
+                return violation("markup_rate is not supported");
+            }
+            if matches!(self.acquirer_details, Some(Some(_))) {
+                return violation("acquirer_details is not supported");
+            }
+        }
+
+        let fits = |amount: &Option<Option<Amount>>, currency: &CurrencyInput| match (
+            amount,
+            currency.build("currency"),
+        ) {
+            (Some(Some(amount)), Ok(currency)) => amount.fits_currency(currency),
+            _ => true,
+        };
+        if !fits(&self.source_amount, &self.source_currency_code) {
+            return violation("source_amount exceeds the minor units of the source currency");
+        }
+        if !fits(&self.destination_amount, &self.destination_currency_code) {
+            return violation(
+                "destination_amount exceeds the minor units of the destination currency",
+            );
+        }
+
+        Ok(())
+    }
+}
+
 /// Details about the acquiring institution for requests using rate product
 /// codes A or B.
 #[derive(Clone, Debug, Serialize, Deserialize, Builder)]
@@ -194,11 +306,19 @@ pub struct AcquirerDetails {
     /// Example: `408999`
     pub bin: u32,
 
-    /// ISO 4217 numeric code of the settlement currency (e.g., "840" for USD).
+    /// ISO 4217 settlement currency, serialized in the numeric form (e.g.,
+    /// "840" for USD).
     ///
     /// Example: `"840"`
-    #[builder(setter(into))]
-    pub settlement_currency_code: String,
+    #[builder(
+        setter(into),
+        field(
+            ty = "CurrencyInput",
+            build = "self.settlement_currency_code.build(\"settlement_currency_code\")?"
+        )
+    )]
+    #[serde(with = "crate::money::currency_numeric")]
+    pub settlement_currency_code: Currency,
 }
 
 /// Foreign Exchange Rates API response structure for rate product codes A or B.
@@ -220,22 +340,22 @@ pub struct FXResponseAorB {
     /// Conversion rate applied to the transaction, excluding any markup.
     ///
     /// Example: `"0.07"`
-    pub conversion_rate: String,
+    pub conversion_rate: Rate,
 
     /// Transaction amount in the destination currency, after conversion.
     ///
     /// Example: `"75.85"`
-    pub destination_amount: String,
+    pub destination_amount: Amount,
 
     /// The FX markup rate applied to the transaction, if any.
     ///
     /// Example: `"0.07"`
-    pub markup_rate_applied: Option<String>,
+    pub markup_rate_applied: Option<Rate>,
 
     /// The destination amount before any markup was applied.
     ///
     /// Example: `"81.16"`
-    pub original_destn_amt_before_mark_up: Option<String>,
+    pub original_destn_amt_before_mark_up: Option<Amount>,
 }
 
 /// Foreign Exchange Rates API response structure for rate product codes BANK or
@@ -260,17 +380,17 @@ pub struct FXResponseBankOrWallet {
     /// amount.
     ///
     /// Example: `0.07`
-    pub conversion_rate: f64,
+    pub conversion_rate: Rate,
 
     /// Source amount in the source currency.
     ///
     /// Example: `100.55`
-    pub source_amount: Option<f64>,
+    pub source_amount: Option<Amount>,
 
     /// Destination amount in the destination currency.
     ///
     /// Example: `75.85`
-    pub destination_amount: Option<f64>,
+    pub destination_amount: Option<Amount>,
 
     /// Unique quote ID for the FX transaction, used in future transactions if
     /// needed.
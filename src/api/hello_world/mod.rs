@@ -1,77 +1,153 @@
+pub mod models;
+
+use crate::api::{
+    response::{parse_response, VisaResponse},
+    result::Result,
+};
 #[double]
 use crate::client::VisaClient;
-use crate::client::{models::ApiLevel, utils::MLETrait};
+use crate::client::{
+    models::RequestOptions,
+    state::{AuthenticationState, WithMutualTls},
+    utils::MLETrait,
+};
 use mockall_double::double;
-use reqwest::{Method, Request};
+use models::*;
+use reqwest::{header, Method, Request};
+use std::time::Instant;
 use url::Url;
 
 /// Visa API for the HelloWorld endpoint. This API is used to test the
 /// connection to the Visa API in certification and production environments.
 ///
+/// The endpoint accepts both Mutual TLS and X-Pay-Token authentication, so
+/// [`HelloWorld`] can be created from a client in either
+/// [`WithMutualTls`] or [`WithXPayToken`][crate::client::state::WithXPayToken]
+/// state.
+///
 /// See
 /// <https://developer.visa.com/pages/working-with-visa-apis/visa-developer-quick-start-guide#section10>
 ///
 /// ## Example
 ///
 /// ```no_run
-/// use visa_sdk::client::state::MutualTls;
-/// use visa_sdk::api::hello_world;
+/// use visa_sdk::client::{models::MutualTlsBuilder, VisaClientBuilder};
+/// use visa_sdk::api::hello_world::HelloWorld;
 ///
-/// let mtls = MutualTls {
-///    user_id: "application-id-from-visa-sama".to_string(),
-///   password: "super-secret-password-from-visa-sama".to_string()
-///       cert: "--- BEGIN ...".to_string(),
-///   cert_key: Some("super-secret-key".to_string()),
-///  ca_bundle: "your_ca_bundle".to_string(),
-/// };
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mtls = MutualTlsBuilder::default()
+///     .user_id("application-id-from-visa")
+///     .password("super-secret-password-from-visa")
+///     .cert(std::fs::read("client.p12")?)
+///     .cert_key(Some(String::from("super-secret-key")))
+///     .build()?;
 ///
-/// let client = VisaClient::builder()
-///    .set_mutual_tls(mtls)
-///    .build();
+/// let client = VisaClientBuilder::new()
+///     .set_mutual_tls(mtls)
+///     .build()?;
 ///
-/// let hello_world = HelloWorld::new(client, false);
-/// let response = hello_world.get().await;
-/// println!("{:?}", response);
+/// let hello_world = HelloWorld::new(client);
+/// let ping = hello_world.ping().await?;
+/// println!("{:?} answered in {:?}", ping.api_level, ping.latency);
+/// # Ok(())
+/// # }
 /// ```
 #[derive(Clone)]
-pub struct HelloWorld<MLE>
+pub struct HelloWorld<MLE, AUTH = WithMutualTls>
 where
     MLE: MLETrait,
+    AUTH: AuthenticationState,
 {
-    client: VisaClient<MLE>,
+    client: VisaClient<MLE, AUTH>,
     url: Url,
+    options: RequestOptions,
 }
 
-impl<MLE> HelloWorld<MLE>
+impl<MLE, AUTH> HelloWorld<MLE, AUTH>
 where
     MLE: MLETrait,
+    AUTH: AuthenticationState,
 {
-    pub fn new(client: VisaClient<MLE>) -> Self {
+    pub fn new(client: VisaClient<MLE, AUTH>) -> Self {
         let base_url = client.get_base_url();
-        let url = match client.get_config().api_level {
-            ApiLevel::Production => base_url.join("/helloworld"),
-            _ => base_url.join("/vdp/helloworld"),
+        let url = match client.get_config().uses_vdp_prefix() {
+            true => base_url.join("vdp/helloworld"),
+            false => base_url.join("helloworld"),
         }
         .unwrap();
-        HelloWorld { client, url }
+        HelloWorld {
+            client,
+            url,
+            options: RequestOptions::default(),
+        }
     }
 
-    pub async fn get(&self) -> serde_json::Value {
-        let request = Request::new(Method::GET, self.url.clone());
-        // TODO: handle the unwraps
-        let response = self.client.execute_request(request).await.unwrap();
-        response.json::<serde_json::Value>().await.unwrap()
+    /// Sets the [`RequestOptions`] of every call. A correlation ID set here is
+    /// shared by all the calls.
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Calls the HelloWorld endpoint.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "hello_world.get",
+            skip_all,
+            fields(
+                method = "GET",
+                path = %self.url.path(),
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn get(&self) -> Result<VisaResponse<HelloWorldResponse>> {
+        let mut request = Request::new(Method::GET, self.url.clone());
+        request.headers_mut().insert(
+            header::ACCEPT,
+            header::HeaderValue::from_static("application/json"),
+        );
+        let response = self
+            .client
+            .execute_request_with_options(request, &self.options)
+            .await?;
+        parse_response::<HelloWorldResponse>(response).await
+    }
+
+    /// Calls the HelloWorld endpoint, and reports the round-trip latency and
+    /// the [`ApiLevel`][crate::client::models::ApiLevel] that answered. Any failure, including a non-2xx
+    /// status, is returned as an error, so it can be used as a readiness
+    /// check.
+    pub async fn ping(&self) -> Result<Ping> {
+        let started = Instant::now();
+        let response = self.get().await?;
+        Ok(Ping {
+            api_level: self.client.get_config().api_level,
+            latency: started.elapsed(),
+            correlation_id: response.correlation_id,
+            response: response.body,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::result::Error;
+    use crate::client::models::{ApiLevel, Config, RequestOptionsBuilder, VdpPrefix};
+    use crate::client::state::WithXPayToken;
     use http::response::Builder as ResponseBuilder;
-    use serde_json::json;
 
     const MOCK_URL: &str = "https://domain.test";
 
+    fn hello_world_response() -> HelloWorldResponse {
+        HelloWorldResponse {
+            timestamp: "2024-01-08T10:22:15".to_string(),
+            message: "helloworld".to_string(),
+        }
+    }
+
     fn setup_mock_execute_request(
         mock_client: &mut VisaClient<()>,
         url: &str,
@@ -85,18 +161,16 @@ mod tests {
 
         let url_clone = url.to_string();
         mock_client
-            .expect_execute_request()
-            .withf(move |request| request.url().as_str() == url_clone)
-            .returning(move |_| Ok(response.clone().into()));
+            .expect_execute_request_with_options()
+            .withf(move |request, _| request.url().as_str() == url_clone)
+            .returning(move |_, _| Ok(response.clone().into()));
     }
 
     fn setup_mock_get_config(mock_client: &mut VisaClient<()>, api_level: ApiLevel) {
-        mock_client
-            .expect_get_config()
-            .return_const(crate::client::models::Config {
-                api_level,
-                ..Default::default()
-            });
+        mock_client.expect_get_config().return_const(Config {
+            api_level,
+            ..Default::default()
+        });
         mock_client
             .expect_get_base_url()
             .return_const(Url::parse(MOCK_URL).unwrap());
@@ -109,14 +183,14 @@ mod tests {
             &mut mock_client,
             format!("{}/vdp/helloworld", self::MOCK_URL).as_str(),
             200,
-            r#"{"message": "Hello, World!"}"#,
+            r#"{"timestamp": "2024-01-08T10:22:15", "message": "helloworld"}"#,
         );
         setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
 
         let hello_world = HelloWorld::new(mock_client);
-        let result = hello_world.get().await;
+        let result = hello_world.get().await.expect("Failed to get response");
 
-        assert_eq!(result, json!({"message": "Hello, World!"}));
+        assert_eq!(result.body, hello_world_response());
     }
 
     #[tokio::test]
@@ -126,14 +200,14 @@ mod tests {
             &mut mock_client,
             format!("{}/vdp/helloworld", self::MOCK_URL).as_str(),
             200,
-            r#"{"message": "Hello, World!"}"#,
+            r#"{"timestamp": "2024-01-08T10:22:15", "message": "helloworld"}"#,
         );
         setup_mock_get_config(&mut mock_client, ApiLevel::Certification);
 
         let hello_world = HelloWorld::new(mock_client);
-        let result = hello_world.get().await;
+        let result = hello_world.get().await.expect("Failed to get response");
 
-        assert_eq!(result, json!({"message": "Hello, World!"}));
+        assert_eq!(result.body, hello_world_response());
     }
 
     #[tokio::test]
@@ -143,13 +217,146 @@ mod tests {
             &mut mock_client,
             format!("{}/helloworld", self::MOCK_URL).as_str(),
             200,
-            r#"{"message": "Hello, World!"}"#,
+            r#"{"timestamp": "2024-01-08T10:22:15", "message": "helloworld"}"#,
         );
         setup_mock_get_config(&mut mock_client, ApiLevel::Production);
 
         let hello_world = HelloWorld::new(mock_client);
-        let result = hello_world.get().await;
+        let result = hello_world.get().await.expect("Failed to get response");
+
+        assert_eq!(result.body, hello_world_response());
+    }
+
+    #[tokio::test]
+    async fn test_hello_world_ping() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(
+            &mut mock_client,
+            format!("{}/helloworld", self::MOCK_URL).as_str(),
+            200,
+            r#"{"timestamp": "2024-01-08T10:22:15", "message": "helloworld"}"#,
+        );
+        setup_mock_get_config(&mut mock_client, ApiLevel::Production);
+
+        let hello_world = HelloWorld::new(mock_client);
+        let ping = hello_world.ping().await.expect("Failed to ping");
+
+        assert_eq!(ping.api_level, ApiLevel::Production);
+        assert_eq!(ping.response, hello_world_response());
+    }
+
+    #[tokio::test]
+    async fn test_hello_world_get_with_x_pay_token() {
+        let mut mock_client = VisaClient::<(), WithXPayToken>::new();
+        let response = ResponseBuilder::new()
+            .status(200)
+            .body(r#"{"timestamp": "2024-01-08T10:22:15", "message": "helloworld"}"#.to_string())
+            .unwrap();
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|request, _| request.url().as_str() == "https://domain.test/vdp/helloworld")
+            .returning(move |_, _| Ok(response.clone().into()));
+        mock_client.expect_get_config().return_const(Config {
+            api_level: ApiLevel::Sandbox,
+            ..Default::default()
+        });
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse(MOCK_URL).unwrap());
+
+        let hello_world: HelloWorld<(), WithXPayToken> = HelloWorld::new(mock_client);
+        let result = hello_world.get().await.expect("Failed to get response");
+
+        assert_eq!(result.body, hello_world_response());
+    }
+
+    #[tokio::test]
+    async fn test_hello_world_get_with_request_options() {
+        let mut mock_client = VisaClient::<()>::new();
+        let response = ResponseBuilder::new()
+            .status(200)
+            .body(r#"{"timestamp": "2024-01-08T10:22:15", "message": "helloworld"}"#.to_string())
+            .unwrap();
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|_, options| options.correlation_id.as_deref() == Some("corr-123"))
+            .returning(move |_, _| Ok(response.clone().into()));
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let hello_world = HelloWorld::new(mock_client).with_request_options(
+            RequestOptionsBuilder::default()
+                .correlation_id("corr-123")
+                .build()
+                .unwrap(),
+        );
+        let result = hello_world.get().await.expect("Failed to get response");
+
+        assert_eq!(result.body, hello_world_response());
+    }
+
+    #[tokio::test]
+    async fn test_hello_world_get_error() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(
+            &mut mock_client,
+            format!("{}/vdp/helloworld", self::MOCK_URL).as_str(),
+            401,
+            r#"{"responseStatus": {"status": 401, "code": "9124", "severity": "ERROR", "message": "Incorrect credentials supplied", "info": ""}}"#,
+        );
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let hello_world = HelloWorld::new(mock_client);
+
+        assert!(matches!(
+            hello_world.ping().await,
+            Err(Error::VisaApiError(error)) if error.code() == Some("9124")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_hello_world_get_through_proxy() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(
+            &mut mock_client,
+            "https://proxy.test/visa/vdp/helloworld",
+            200,
+            r#"{"timestamp": "2024-01-08T10:22:15", "message": "helloworld"}"#,
+        );
+        mock_client.expect_get_config().return_const(Config {
+            api_level: ApiLevel::Production,
+            base_url: Some(Url::parse("https://proxy.test/visa").unwrap()),
+            vdp_prefix: VdpPrefix::Always,
+        });
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse("https://proxy.test/visa/").unwrap());
+
+        let hello_world = HelloWorld::new(mock_client);
+        let result = hello_world.get().await.expect("Failed to get response");
+
+        assert_eq!(result.body, hello_world_response());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_hello_world_get_span() {
+        let subscriber = crate::utils::trace::tests::CapturingSubscriber::default();
+        let _guard = tracing::subscriber::set_default(subscriber.clone());
+
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(
+            &mut mock_client,
+            format!("{}/vdp/helloworld", self::MOCK_URL).as_str(),
+            200,
+            r#"{"timestamp": "2024-01-08T10:22:15", "message": "helloworld"}"#,
+        );
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        HelloWorld::new(mock_client).get().await.unwrap();
 
-        assert_eq!(result, json!({"message": "Hello, World!"}));
+        let fields = subscriber.fields.lock().unwrap().join("\n");
+        assert!(fields.contains("span=hello_world.get"));
+        assert!(fields.contains("path=/vdp/helloworld"));
+        assert!(fields.contains("api_level=Sandbox"));
     }
 }
@@ -0,0 +1,46 @@
+//! # HelloWorld Models
+//!
+//! Response models of the HelloWorld endpoint.
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::client::models::ApiLevel;
+
+/// HelloWorld API response structure.
+///
+/// Example response:
+/// ```json
+/// {
+///     "timestamp": "2024-01-08T10:22:15",
+///     "message": "helloworld"
+/// }
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct HelloWorldResponse {
+    /// The time the request was answered, as reported by Visa.
+    ///
+    /// Example: `"2024-01-08T10:22:15"`
+    pub timestamp: String,
+
+    /// The greeting message.
+    ///
+    /// Example: `"helloworld"`
+    pub message: String,
+}
+
+/// The result of a successful [`HelloWorld::ping`][super::HelloWorld::ping].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ping {
+    /// The API level that answered.
+    pub api_level: ApiLevel,
+
+    /// The round-trip time of the request.
+    pub latency: Duration,
+
+    /// The `x-correlation-id` of the call.
+    pub correlation_id: Option<String>,
+
+    /// The HelloWorld response.
+    pub response: HelloWorldResponse,
+}
@@ -2,11 +2,18 @@ pub mod hello_world;
 
 pub mod foreign_exchange;
 
+pub mod visa_direct;
+
 pub mod result;
 
+pub(crate) mod request;
+
+pub(crate) mod response;
+pub use response::VisaResponse;
+
 #[cfg(test)]
 #[doc(hidden)]
-pub(self) mod testkit;
+mod testkit;
 
 #[doc(hidden)]
 pub mod constants;
@@ -0,0 +1,27 @@
+use reqwest::{header, Method, Request};
+use serde::Serialize;
+use url::Url;
+
+use super::result::Result;
+
+/// Builds a request with a JSON body, and the `Content-Type` and `Accept`
+/// headers set to `application/json`.
+pub(crate) fn json_request<T>(method: Method, url: Url, payload: &T) -> Result<Request>
+where
+    T: Serialize + ?Sized,
+{
+    let mut request = Request::new(method, url);
+    let headers = request.headers_mut();
+    headers.insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static("application/json"),
+    );
+    headers.insert(
+        header::ACCEPT,
+        header::HeaderValue::from_static("application/json"),
+    );
+    request
+        .body_mut()
+        .replace(serde_json::to_vec(payload)?.into());
+    Ok(request)
+}
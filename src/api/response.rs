@@ -0,0 +1,234 @@
+use derive_more::{Deref, DerefMut};
+use reqwest::{header::HeaderMap, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+
+use super::result::{Result, VisaApiError};
+
+/// Name of the header carrying Visa's correlation ID.
+pub(crate) const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+/// Metadata of a call, attached to the response extensions by the client.
+#[derive(Clone, Debug)]
+pub(crate) struct CallMetadata {
+    /// The correlation ID sent with the request.
+    pub(crate) correlation_id: String,
+
+    /// The time from sending the request until the response headers, retries
+    /// included.
+    pub(crate) latency: Duration,
+}
+
+/// A successful response of the Visa API: the deserialized body, and the
+/// metadata of the call.
+///
+/// Dereferences to the body.
+#[derive(Clone, Debug, Deref, DerefMut)]
+pub struct VisaResponse<T> {
+    /// The deserialized response body.
+    #[deref]
+    #[deref_mut]
+    pub body: T,
+
+    /// The HTTP status of the response.
+    pub status: StatusCode,
+
+    /// The `x-correlation-id` of the call, as returned by Visa, or as sent by
+    /// the client. Visa support asks for this value when investigating a
+    /// call.
+    pub correlation_id: Option<String>,
+
+    /// The response headers.
+    pub headers: HeaderMap,
+
+    /// The time from sending the request until the response headers, retries
+    /// included.
+    pub latency: Duration,
+}
+
+impl<T> VisaResponse<T> {
+    /// Discards the metadata, and returns the body.
+    pub fn into_body(self) -> T {
+        self.body
+    }
+}
+
+/// Maps a Visa API response into a [`VisaResponse`] of `T`.
+///
+/// Non-2xx responses are turned into a [`VisaApiError`] before any attempt to
+/// deserialize the body as `T`.
+pub(crate) async fn parse_response<T>(response: Response) -> Result<VisaResponse<T>>
+where
+    T: DeserializeOwned,
+{
+    if !response.status().is_success() {
+        return Err(api_error(response).await.into());
+    }
+
+    let status = response.status();
+    let headers = response.headers().clone();
+    let correlation_id = correlation_id(&response);
+    let latency = response
+        .extensions()
+        .get::<CallMetadata>()
+        .map(|metadata| metadata.latency)
+        .unwrap_or_default();
+    let body = response.bytes().await?;
+
+    Ok(VisaResponse {
+        body: serde_json::from_slice(&body)?,
+        status,
+        correlation_id,
+        headers,
+        latency,
+    })
+}
+
+/// The correlation ID returned by Visa, or else the one sent by the client.
+pub(crate) fn correlation_id(response: &Response) -> Option<String> {
+    response
+        .headers()
+        .get(CORRELATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(String::from)
+        .or_else(|| {
+            let metadata = response.extensions().get::<CallMetadata>()?;
+            Some(metadata.correlation_id.clone())
+        })
+}
+
+/// Builds a [`VisaApiError`] out of a non-2xx response.
+pub(crate) async fn api_error(response: Response) -> VisaApiError {
+    let status = response.status();
+    let correlation_id = correlation_id(&response);
+    let raw_body = response.text().await.unwrap_or_default();
+    let body =
+        serde_json::from_str(&raw_body)
+            .ok()
+            .filter(|body: &super::result::VisaErrorBody| {
+                body.response_status.is_some() || body.error_info.is_some()
+            });
+
+    VisaApiError {
+        status,
+        correlation_id,
+        body,
+        raw_body,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::result::Error;
+    use http::response::Builder as ResponseBuilder;
+
+    fn response(status: u16, body: &str) -> Response {
+        ResponseBuilder::new()
+            .status(status)
+            .header(CORRELATION_ID_HEADER, "1234_abcd")
+            .body(body.to_string())
+            .unwrap()
+            .into()
+    }
+
+    #[tokio::test]
+    async fn test_parse_response_metadata() {
+        let mut response = response(200, r#"{"ping":"pong"}"#);
+        response.extensions_mut().insert(CallMetadata {
+            correlation_id: "sent".to_string(),
+            latency: Duration::from_millis(42),
+        });
+
+        let response = parse_response::<serde_json::Value>(response).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response["ping"], "pong");
+        // The correlation ID returned by Visa wins.
+        assert_eq!(response.correlation_id.as_deref(), Some("1234_abcd"));
+        assert_eq!(response.latency, Duration::from_millis(42));
+        assert!(response.headers.contains_key(CORRELATION_ID_HEADER));
+    }
+
+    #[tokio::test]
+    async fn test_parse_response_sent_correlation_id() {
+        let mut response: Response = ResponseBuilder::new()
+            .status(502)
+            .body("Bad Gateway")
+            .unwrap()
+            .into();
+        response.extensions_mut().insert(CallMetadata {
+            correlation_id: "sent".to_string(),
+            latency: Duration::ZERO,
+        });
+
+        let Err(Error::VisaApiError(error)) = parse_response::<serde_json::Value>(response).await
+        else {
+            panic!("expected a VisaApiError");
+        };
+        assert_eq!(error.correlation_id.as_deref(), Some("sent"));
+    }
+
+    #[tokio::test]
+    async fn test_parse_response_response_status() {
+        let body = r#"{
+            "responseStatus": {
+                "status": 400,
+                "code": "9125",
+                "severity": "ERROR",
+                "message": "Expected input credential was not present",
+                "info": ""
+            }
+        }"#;
+        let Err(Error::VisaApiError(error)) =
+            parse_response::<serde_json::Value>(response(400, body)).await
+        else {
+            panic!("expected a VisaApiError");
+        };
+
+        assert_eq!(error.status, 400);
+        assert_eq!(error.code(), Some("9125"));
+        assert_eq!(error.severity(), Some("ERROR"));
+        assert_eq!(
+            error.message(),
+            Some("Expected input credential was not present")
+        );
+        assert_eq!(error.info(), Some(""));
+        assert_eq!(error.correlation_id.as_deref(), Some("1234_abcd"));
+        assert_eq!(error.raw_body, body);
+    }
+
+    #[tokio::test]
+    async fn test_parse_response_error_info() {
+        let body = r#"{
+            "errorInfo": {
+                "name": "InvalidParameter",
+                "reason": "InvalidParameter",
+                "message": "Invalid rate product code",
+                "details": [{ "location": "rate_product_code" }]
+            }
+        }"#;
+        let Err(Error::VisaApiError(error)) =
+            parse_response::<serde_json::Value>(response(422, body)).await
+        else {
+            panic!("expected a VisaApiError");
+        };
+
+        assert_eq!(error.status, 422);
+        assert_eq!(error.code(), Some("InvalidParameter"));
+        assert_eq!(error.message(), Some("Invalid rate product code"));
+        assert_eq!(error.body.unwrap().error_info.unwrap().details.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_parse_response_unknown_error_body() {
+        let Err(Error::VisaApiError(error)) =
+            parse_response::<serde_json::Value>(response(502, "Bad Gateway")).await
+        else {
+            panic!("expected a VisaApiError");
+        };
+
+        assert_eq!(error.status, 502);
+        assert_eq!(error.body, None);
+        assert_eq!(error.raw_body, "Bad Gateway");
+    }
+}
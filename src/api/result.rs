@@ -1,4 +1,5 @@
 use derive_more::From;
+use serde::{Deserialize, Serialize};
 
 pub type Result<T> = core::result::Result<T, Error>;
 
@@ -7,9 +8,19 @@ pub enum Error {
     #[from]
     Custom(String),
 
+    /// Failed to encrypt a request or decrypt a response using Message Level
+    /// Encryption.
+    MessageLevelEncryption(String),
+
+    /// Visa answered with a non-2xx status.
+    VisaApiError(Box<VisaApiError>),
+
     // -- Externals
     #[from]
-    RequestError(reqwest::Error)
+    RequestError(reqwest::Error),
+
+    #[from]
+    Json(serde_json::Error),
 }
 
 // region:    --- Custom
@@ -18,6 +29,16 @@ impl Error {
     pub fn custom(val: impl std::fmt::Display) -> Self {
         Self::Custom(val.to_string())
     }
+
+    pub(crate) fn message_level_encryption(val: impl std::fmt::Display) -> Self {
+        Self::MessageLevelEncryption(val.to_string())
+    }
+}
+
+impl From<VisaApiError> for Error {
+    fn from(val: VisaApiError) -> Self {
+        Self::VisaApiError(Box::new(val))
+    }
 }
 
 impl From<&str> for Error {
@@ -28,6 +49,141 @@ impl From<&str> for Error {
 
 // endregion: --- Custom
 
+// region:    --- Visa API Error
+
+/// A non-2xx response from the Visa API.
+///
+/// Visa reports errors either in a `responseStatus` or in an `errorInfo`
+/// object. The common fields of both are exposed on this struct, while the
+/// parsed body and the raw body are kept for anything else.
+///
+/// Example response:
+/// ```json
+/// {
+///     "responseStatus": {
+///         "status": 400,
+///         "code": "9125",
+///         "severity": "ERROR",
+///         "message": "Expected input credential was not present",
+///         "info": ""
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VisaApiError {
+    /// The HTTP status of the response.
+    pub status: reqwest::StatusCode,
+
+    /// The `x-correlation-id` of the call, as returned by Visa, or as sent by
+    /// the client. Visa support asks for this value when investigating a
+    /// failing call.
+    pub correlation_id: Option<String>,
+
+    /// The parsed error body, if the response body is in one of Visa's error
+    /// formats.
+    pub body: Option<VisaErrorBody>,
+
+    /// The raw response body.
+    pub raw_body: String,
+}
+
+impl VisaApiError {
+    /// Visa's error code, e.g. `"9125"`.
+    pub fn code(&self) -> Option<&str> {
+        let body = self.body.as_ref()?;
+        body.response_status
+            .as_ref()
+            .and_then(|status| status.code.as_deref())
+            .or_else(|| body.error_info.as_ref()?.reason.as_deref())
+    }
+
+    /// The severity of the error, e.g. `"ERROR"`.
+    pub fn severity(&self) -> Option<&str> {
+        self.body
+            .as_ref()?
+            .response_status
+            .as_ref()?
+            .severity
+            .as_deref()
+    }
+
+    /// The human-readable error message.
+    pub fn message(&self) -> Option<&str> {
+        let body = self.body.as_ref()?;
+        body.response_status
+            .as_ref()
+            .and_then(|status| status.message.as_deref())
+            .or_else(|| body.error_info.as_ref()?.message.as_deref())
+    }
+
+    /// Additional information about the error.
+    pub fn info(&self) -> Option<&str> {
+        let body = self.body.as_ref()?;
+        body.response_status
+            .as_ref()
+            .and_then(|status| status.info.as_deref())
+            .or_else(|| body.error_info.as_ref()?.name.as_deref())
+    }
+}
+
+impl core::fmt::Display for VisaApiError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(fmt, "Visa API error {}", self.status)?;
+        if let Some(code) = self.code() {
+            write!(fmt, " [{code}]")?;
+        }
+        if let Some(message) = self.message() {
+            write!(fmt, ": {message}")?;
+        }
+        if let Some(correlation_id) = &self.correlation_id {
+            write!(fmt, " (correlation ID: {correlation_id})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Error body returned by the Visa API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VisaErrorBody {
+    /// Error format used by most of the Visa APIs.
+    pub response_status: Option<ResponseStatus>,
+
+    /// Error format used by the newer Visa APIs.
+    pub error_info: Option<ErrorInfo>,
+}
+
+/// The `responseStatus` error object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponseStatus {
+    /// The HTTP status, as reported in the body.
+    pub status: Option<u16>,
+    /// Visa's error code, e.g. `"9125"`.
+    pub code: Option<String>,
+    /// The severity of the error, e.g. `"ERROR"`.
+    pub severity: Option<String>,
+    /// The human-readable error message.
+    pub message: Option<String>,
+    /// Additional information about the error.
+    pub info: Option<String>,
+}
+
+/// The `errorInfo` error object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorInfo {
+    /// The name of the error.
+    pub name: Option<String>,
+    /// Visa's error reason code.
+    pub reason: Option<String>,
+    /// The human-readable error message.
+    pub message: Option<String>,
+    /// Field level details of the error, if any.
+    #[serde(default)]
+    pub details: Vec<serde_json::Value>,
+}
+
+// endregion: --- Visa API Error
+
 // region:    --- Error Boilerplate
 
 impl core::fmt::Display for Error {
@@ -0,0 +1,54 @@
+//! Payloads shared by the tests of the Visa Direct APIs.
+
+use crate::api::visa_direct::{
+    funds_transfer::models::PushFundsRequestBuilder,
+    models::{CardAcceptor, CardAcceptorAddressBuilder, CardAcceptorBuilder},
+};
+use crate::money::Amount;
+use serde_json::json;
+
+/// The card acceptor of Visa's sandbox samples.
+pub(crate) fn card_acceptor() -> CardAcceptor {
+    CardAcceptorBuilder::default()
+        .name("Visa Inc. USA-Foster City")
+        .terminal_id("TID-9999")
+        .id_code("CA-IDCode-77765")
+        .address(
+            CardAcceptorAddressBuilder::default()
+                .country("USA")
+                .state(Some("CA".to_string()))
+                .zip_code(Some("94404".to_string()))
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap()
+}
+
+/// [`card_acceptor`], as sent to Visa.
+pub(crate) fn card_acceptor_json() -> serde_json::Value {
+    json!({
+        "name": "Visa Inc. USA-Foster City",
+        "terminalId": "TID-9999",
+        "idCode": "CA-IDCode-77765",
+        "address": { "country": "USA", "state": "CA", "zipCode": "94404" }
+    })
+}
+
+/// A push of 124.05 USD from acquiring BIN 408999, with the RRN `412770`
+/// followed by the STAN.
+pub(crate) fn push_builder(stan: u32) -> PushFundsRequestBuilder {
+    let mut builder = PushFundsRequestBuilder::default();
+    builder
+        .systems_trace_audit_number(stan)
+        .retrieval_reference_number(format!("412770{stan:06}"))
+        .local_transaction_date_time("2024-01-08T10:22:15")
+        .acquiring_bin(408999)
+        .acquirer_country_code("840")
+        .recipient_primary_account_number("4957030420210496")
+        .transaction_currency_code("USD")
+        .amount(Amount::new(12405, 2))
+        .business_application_id("PP")
+        .card_acceptor(card_acceptor());
+    builder
+}
@@ -0,0 +1,675 @@
+//! # Funds Transfer API Module
+//!
+//! This module provides access to the Visa Direct Funds Transfer API, which
+//! pulls funds from a sender's card, pushes funds to a recipient's card, and
+//! reverses pulls.
+//!
+//! ## Overview
+//!
+//! The [`FundsTransfer`] struct is the main entry point. Each transaction type
+//! has a method to submit it, and a method to look up the status of a
+//! submission still in progress:
+//!
+//! | Transaction | Submit                       | Look up                             |
+//! |-------------|------------------------------|-------------------------------------|
+//! | Pull (AFT)  | [`FundsTransfer::pull`]      | [`FundsTransfer::pull_status`]      |
+//! | Push (OCT)  | [`FundsTransfer::push`]      | [`FundsTransfer::push_status`]      |
+//! | Reversal    | [`FundsTransfer::reverse`]   | [`FundsTransfer::reverse_status`]   |
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use visa_sdk::client::{models::MutualTls, VisaClientBuilder};
+//! use visa_sdk::api::visa_direct::funds_transfer::{models::PushFundsRequest, FundsTransfer};
+//!
+//! # async fn example(payload: PushFundsRequest) -> Result<(), Box<dyn std::error::Error>> {
+//! # let mtls = MutualTls::default();
+//! let client = VisaClientBuilder::new().set_mutual_tls(mtls).build()?;
+//! let funds_transfer = FundsTransfer::new(client);
+//!
+//! let mut response = funds_transfer.push(payload).await?;
+//! while response.is_pending() {
+//!     tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+//!     let status_identifier = response.status_identifier.clone().unwrap();
+//!     response = funds_transfer.push_status(&status_identifier).await?;
+//! }
+//! println!("approved: {}", response.is_approved());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Documentation
+//!
+//! For more details, consult the Visa API documentation:
+//! - [Visa Direct Funds Transfer API
+//!   Reference](https://developer.visa.com/capabilities/visa_direct/reference#tag/Funds-Transfer-API)
+
+pub mod models;
+
+#[double]
+use crate::client::VisaClient;
+use crate::{
+    api::{
+        request::json_request,
+        response::{parse_response, VisaResponse},
+        result::Result,
+    },
+    client::{models::RequestOptions, utils::MLETrait},
+};
+use mockall_double::double;
+use models::*;
+use reqwest::{header, Method, Request};
+use serde::Serialize;
+use url::Url;
+
+/// [`FundsTransfer`] struct provides methods to interact with the Visa Direct
+/// Funds Transfer API.
+///
+/// The submissions are `POST` requests, so the client does not retry them
+/// unless [`RequestOptions::idempotent`][crate::client::models::RequestOptionsBuilder::idempotent]
+/// says so: a retried push that reached Visa is a second payout. The status
+/// lookups are retried as any `GET`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use visa_sdk::client::{state::WithoutMessageLevelEncryption, VisaClient};
+/// use std::time::Duration;
+/// use visa_sdk::api::visa_direct::funds_transfer::FundsTransfer;
+/// use visa_sdk::client::models::RequestOptionsBuilder;
+///
+/// # fn example(client: VisaClient<WithoutMessageLevelEncryption>) {
+/// let funds_transfer = FundsTransfer::new(client).with_request_options(
+///     RequestOptionsBuilder::default()
+///         .timeout(Duration::from_secs(30))
+///         .build()
+///         .unwrap(),
+/// );
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FundsTransfer<MLE>
+where
+    MLE: MLETrait,
+{
+    client: VisaClient<MLE>,
+    url: Url,
+    options: RequestOptions,
+}
+
+impl<MLE> FundsTransfer<MLE>
+where
+    MLE: MLETrait,
+{
+    const URL: &'static str = "visadirect/fundstransfer/v1/";
+    const PULL: &'static str = "pullfundstransactions";
+    const PUSH: &'static str = "pushfundstransactions";
+    const REVERSE: &'static str = "reversefundstransactions";
+
+    /// Creates a new instance of `FundsTransfer`.
+    ///
+    /// # Arguments
+    ///
+    /// - `client`: A `VisaClient` instance. Does not need to have Message Level
+    ///   Encryption enabled.
+    pub fn new(client: VisaClient<MLE>) -> Self {
+        let url = client.get_base_url().join(Self::URL).unwrap();
+        FundsTransfer {
+            client,
+            url,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Sets the [`RequestOptions`] of every call. A correlation ID set here is
+    /// shared by all the calls.
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Submits a Pull Funds Transaction, debiting the sender's card.
+    ///
+    /// # Arguments
+    ///
+    /// - `payload`: An instance of `PullFundsRequest`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "funds_transfer.pull",
+            skip_all,
+            fields(
+                method = "POST",
+                path = "/visadirect/fundstransfer/v1/pullfundstransactions",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn pull(
+        &self,
+        payload: PullFundsRequest,
+    ) -> Result<VisaResponse<FundsTransferResponse>> {
+        self.submit(Self::PULL, &payload).await
+    }
+
+    /// Looks up the status of a Pull Funds Transaction which was still in
+    /// progress.
+    ///
+    /// # Arguments
+    ///
+    /// - `status_identifier`: The
+    ///   [`status_identifier`][FundsTransferResponse::status_identifier] of
+    ///   the submission.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "funds_transfer.pull_status",
+            skip_all,
+            fields(
+                method = "GET",
+                path = "/visadirect/fundstransfer/v1/pullfundstransactions/{statusIdentifier}",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn pull_status(
+        &self,
+        status_identifier: &str,
+    ) -> Result<VisaResponse<FundsTransferResponse>> {
+        self.status(Self::PULL, status_identifier).await
+    }
+
+    /// Submits a Push Funds Transaction, crediting the recipient's card.
+    ///
+    /// # Arguments
+    ///
+    /// - `payload`: An instance of `PushFundsRequest`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "funds_transfer.push",
+            skip_all,
+            fields(
+                method = "POST",
+                path = "/visadirect/fundstransfer/v1/pushfundstransactions",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn push(
+        &self,
+        payload: PushFundsRequest,
+    ) -> Result<VisaResponse<FundsTransferResponse>> {
+        self.submit(Self::PUSH, &payload).await
+    }
+
+    /// Looks up the status of a Push Funds Transaction which was still in
+    /// progress.
+    ///
+    /// # Arguments
+    ///
+    /// - `status_identifier`: The
+    ///   [`status_identifier`][FundsTransferResponse::status_identifier] of
+    ///   the submission.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "funds_transfer.push_status",
+            skip_all,
+            fields(
+                method = "GET",
+                path = "/visadirect/fundstransfer/v1/pushfundstransactions/{statusIdentifier}",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn push_status(
+        &self,
+        status_identifier: &str,
+    ) -> Result<VisaResponse<FundsTransferResponse>> {
+        self.status(Self::PUSH, status_identifier).await
+    }
+
+    /// Submits a Reverse Funds Transaction, crediting back the sender's card
+    /// for a pull.
+    ///
+    /// # Arguments
+    ///
+    /// - `payload`: An instance of `ReverseFundsRequest`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "funds_transfer.reverse",
+            skip_all,
+            fields(
+                method = "POST",
+                path = "/visadirect/fundstransfer/v1/reversefundstransactions",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn reverse(
+        &self,
+        payload: ReverseFundsRequest,
+    ) -> Result<VisaResponse<FundsTransferResponse>> {
+        self.submit(Self::REVERSE, &payload).await
+    }
+
+    /// Looks up the status of a Reverse Funds Transaction which was still in
+    /// progress.
+    ///
+    /// # Arguments
+    ///
+    /// - `status_identifier`: The
+    ///   [`status_identifier`][FundsTransferResponse::status_identifier] of
+    ///   the submission.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "funds_transfer.reverse_status",
+            skip_all,
+            fields(
+                method = "GET",
+                path = "/visadirect/fundstransfer/v1/reversefundstransactions/{statusIdentifier}",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn reverse_status(
+        &self,
+        status_identifier: &str,
+    ) -> Result<VisaResponse<FundsTransferResponse>> {
+        self.status(Self::REVERSE, status_identifier).await
+    }
+
+    async fn submit<T>(
+        &self,
+        resource: &str,
+        payload: &T,
+    ) -> Result<VisaResponse<FundsTransferResponse>>
+    where
+        T: Serialize,
+    {
+        let request = json_request(Method::POST, self.url.join(resource).unwrap(), payload)?;
+        let response = self
+            .client
+            .execute_request_with_options(request, &self.options)
+            .await?;
+        parse_response::<FundsTransferResponse>(response).await
+    }
+
+    async fn status(
+        &self,
+        resource: &str,
+        status_identifier: &str,
+    ) -> Result<VisaResponse<FundsTransferResponse>> {
+        let mut url = self.url.join(resource).unwrap();
+        url.path_segments_mut().unwrap().push(status_identifier);
+
+        let mut request = Request::new(Method::GET, url);
+        request.headers_mut().insert(
+            header::ACCEPT,
+            header::HeaderValue::from_static("application/json"),
+        );
+        let response = self
+            .client
+            .execute_request_with_options(request, &self.options)
+            .await?;
+        parse_response::<FundsTransferResponse>(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::result::Error;
+    use crate::api::visa_direct::fixtures::{card_acceptor, card_acceptor_json, push_builder};
+    use crate::client::models::{ApiLevel, Config, RequestOptionsBuilder};
+    use crate::money::Amount;
+    use http::response::Builder as ResponseBuilder;
+    use serde_json::json;
+
+    const MOCK_URL: &str = "https://domain.test";
+
+    fn setup_mock_execute_request(
+        mock_client: &mut VisaClient<()>,
+        method: Method,
+        url: &str,
+        expected_body: Option<serde_json::Value>,
+        status: u16,
+        body: &str,
+    ) {
+        let response = ResponseBuilder::new()
+            .status(status)
+            .body(body.to_string())
+            .unwrap();
+
+        let url_clone = url.to_string();
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(move |request, _| {
+                let body = request
+                    .body()
+                    .and_then(|body| body.as_bytes())
+                    .and_then(|body| serde_json::from_slice::<serde_json::Value>(body).ok());
+                request.method() == method
+                    && request.url().as_str() == url_clone
+                    && request.headers()["accept"] == "application/json"
+                    && body == expected_body
+            })
+            .returning(move |_, _| Ok(response.clone().into()));
+    }
+
+    fn setup_mock_get_config(mock_client: &mut VisaClient<()>, api_level: ApiLevel) {
+        mock_client.expect_get_config().return_const(Config {
+            api_level,
+            ..Default::default()
+        });
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse(MOCK_URL).unwrap());
+    }
+
+    fn push_payload() -> PushFundsRequest {
+        push_builder(451000)
+            .sender_name(Some("Mohammed Qasim".to_string()))
+            .transaction_identifier(Some(381228649430015))
+            .build()
+            .expect("Failed to build PushFundsRequest")
+    }
+
+    const APPROVED: &str = r#"{
+        "transactionIdentifier": 381228649430015,
+        "actionCode": "00",
+        "approvalCode": "20304B",
+        "responseCode": "5",
+        "transmissionDateTime": "2024-01-08T10:22:15.000Z",
+        "feeProgramIndicator": "123"
+    }"#;
+
+    #[tokio::test]
+    async fn test_funds_transfer_pull() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(
+            &mut mock_client,
+            Method::POST,
+            format!(
+                "{}/visadirect/fundstransfer/v1/pullfundstransactions",
+                self::MOCK_URL
+            )
+            .as_str(),
+            Some(json!({
+                "systemsTraceAuditNumber": 451001,
+                "retrievalReferenceNumber": "412770451001",
+                "localTransactionDateTime": "2024-01-08T10:22:15",
+                "acquiringBin": 408999,
+                "acquirerCountryCode": "840",
+                "senderPrimaryAccountNumber": "4895142232120006",
+                "senderCardExpiryDate": "2030-10",
+                "senderCurrencyCode": "USD",
+                "amount": "124.02",
+                "businessApplicationId": "AA",
+                "cardAcceptor": card_acceptor_json(),
+                "surcharge": "1.50"
+            })),
+            200,
+            APPROVED,
+        );
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let funds_transfer = FundsTransfer::new(mock_client);
+        let payload = PullFundsRequestBuilder::default()
+            .systems_trace_audit_number(451001)
+            .retrieval_reference_number("412770451001")
+            .local_transaction_date_time("2024-01-08T10:22:15")
+            .acquiring_bin(408999)
+            .acquirer_country_code("840")
+            .sender_primary_account_number("4895142232120006")
+            .sender_card_expiry_date("2030-10")
+            .sender_currency_code("USD")
+            .amount(Amount::new(12402, 2))
+            .business_application_id("AA")
+            .card_acceptor(card_acceptor())
+            .surcharge(Some(Amount::new(150, 2)))
+            .build()
+            .expect("Failed to build PullFundsRequest");
+
+        let result = funds_transfer
+            .pull(payload)
+            .await
+            .expect("Failed to get response");
+
+        assert!(result.is_approved());
+        assert!(!result.is_pending());
+        assert_eq!(
+            result.body,
+            FundsTransferResponse {
+                transaction_identifier: Some(381228649430015),
+                action_code: Some("00".to_string()),
+                approval_code: Some("20304B".to_string()),
+                response_code: Some("5".to_string()),
+                transmission_date_time: Some("2024-01-08T10:22:15.000Z".to_string()),
+                fee_program_indicator: Some("123".to_string()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_funds_transfer_push_pending() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(
+            &mut mock_client,
+            Method::POST,
+            format!(
+                "{}/visadirect/fundstransfer/v1/pushfundstransactions",
+                self::MOCK_URL
+            )
+            .as_str(),
+            Some(json!({
+                "systemsTraceAuditNumber": 451000,
+                "retrievalReferenceNumber": "412770451000",
+                "localTransactionDateTime": "2024-01-08T10:22:15",
+                "acquiringBin": 408999,
+                "acquirerCountryCode": "840",
+                "recipientPrimaryAccountNumber": "4957030420210496",
+                "transactionCurrencyCode": "USD",
+                "amount": "124.05",
+                "businessApplicationId": "PP",
+                "cardAcceptor": card_acceptor_json(),
+                "senderName": "Mohammed Qasim",
+                "transactionIdentifier": 381228649430015u64
+            })),
+            202,
+            r#"{"statusIdentifier": "234234322342343"}"#,
+        );
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let funds_transfer = FundsTransfer::new(mock_client);
+        let result = funds_transfer
+            .push(push_payload())
+            .await
+            .expect("Failed to get response");
+
+        assert_eq!(result.status, 202);
+        assert!(result.is_pending());
+        assert!(!result.is_approved());
+        assert_eq!(result.status_identifier.as_deref(), Some("234234322342343"));
+    }
+
+    #[tokio::test]
+    async fn test_funds_transfer_push_status() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(
+            &mut mock_client,
+            Method::GET,
+            format!(
+                "{}/visadirect/fundstransfer/v1/pushfundstransactions/2342%2F43",
+                self::MOCK_URL
+            )
+            .as_str(),
+            None,
+            200,
+            APPROVED,
+        );
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let funds_transfer = FundsTransfer::new(mock_client);
+        let result = funds_transfer
+            .push_status("2342/43")
+            .await
+            .expect("Failed to get response");
+
+        assert!(result.is_approved());
+    }
+
+    #[tokio::test]
+    async fn test_funds_transfer_reverse() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(
+            &mut mock_client,
+            Method::POST,
+            format!(
+                "{}/visadirect/fundstransfer/v1/reversefundstransactions",
+                self::MOCK_URL
+            )
+            .as_str(),
+            Some(json!({
+                "systemsTraceAuditNumber": 451050,
+                "retrievalReferenceNumber": "412770451050",
+                "localTransactionDateTime": "2024-01-08T10:32:15",
+                "acquiringBin": 408999,
+                "acquirerCountryCode": "840",
+                "senderPrimaryAccountNumber": "4895142232120006",
+                "senderCardExpiryDate": "2030-10",
+                "senderCurrencyCode": "USD",
+                "amount": "124.02",
+                "transactionIdentifier": 381228649430015u64,
+                "originalDataElements": {
+                    "approvalCode": "20304B",
+                    "systemsTraceAuditNumber": 451001,
+                    "transmissionDateTime": "2024-01-08T10:22:15",
+                    "acquiringBin": 408999
+                },
+                "cardAcceptor": card_acceptor_json()
+            })),
+            200,
+            APPROVED,
+        );
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let funds_transfer = FundsTransfer::new(mock_client);
+        let payload = ReverseFundsRequestBuilder::default()
+            .systems_trace_audit_number(451050)
+            .retrieval_reference_number("412770451050")
+            .local_transaction_date_time("2024-01-08T10:32:15")
+            .acquiring_bin(408999)
+            .acquirer_country_code("840")
+            .sender_primary_account_number("4895142232120006")
+            .sender_card_expiry_date("2030-10")
+            .sender_currency_code("USD")
+            .amount(Amount::new(12402, 2))
+            .transaction_identifier(381228649430015)
+            .original_data_elements(
+                OriginalDataElementsBuilder::default()
+                    .approval_code("20304B")
+                    .systems_trace_audit_number(451001)
+                    .transmission_date_time("2024-01-08T10:22:15")
+                    .acquiring_bin(408999)
+                    .build()
+                    .unwrap(),
+            )
+            .card_acceptor(card_acceptor())
+            .build()
+            .expect("Failed to build ReverseFundsRequest");
+
+        let result = funds_transfer
+            .reverse(payload)
+            .await
+            .expect("Failed to get response");
+
+        assert!(result.is_approved());
+    }
+
+    #[tokio::test]
+    async fn test_funds_transfer_maps_visa_error() {
+        let mut mock_client = VisaClient::<()>::new();
+        mock_client
+            .expect_execute_request_with_options()
+            .returning(|_, _| {
+                Ok(ResponseBuilder::new()
+                    .status(400)
+                    .body(
+                        r#"{"errorInfo": {"name": "InvalidParameter", "reason": "InvalidParameter", "message": "Invalid RRN"}}"#,
+                    )
+                    .unwrap()
+                    .into())
+            });
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let funds_transfer = FundsTransfer::new(mock_client);
+
+        let Err(Error::VisaApiError(error)) = funds_transfer.push(push_payload()).await else {
+            panic!("expected a VisaApiError");
+        };
+        assert_eq!(error.status, 400);
+        assert_eq!(error.code(), Some("InvalidParameter"));
+    }
+
+    #[tokio::test]
+    async fn test_funds_transfer_request_options() {
+        let mut mock_client = VisaClient::<()>::new();
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|_, options| {
+                options.idempotent.is_none() && options.correlation_id.as_deref() == Some("batch-7")
+            })
+            .returning(|_, _| {
+                Ok(ResponseBuilder::new()
+                    .status(200)
+                    .body(APPROVED)
+                    .unwrap()
+                    .into())
+            });
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let funds_transfer = FundsTransfer::new(mock_client).with_request_options(
+            RequestOptionsBuilder::default()
+                .correlation_id("batch-7")
+                .build()
+                .unwrap(),
+        );
+
+        assert!(funds_transfer.push(push_payload()).await.is_ok());
+    }
+
+    #[test]
+    fn test_funds_transfer_request_rejects_invalid_identification() {
+        assert!(push_builder(451000).build().is_ok());
+
+        for builder in [
+            push_builder(451000)
+                .systems_trace_audit_number(1_000_000)
+                .clone(),
+            push_builder(451000)
+                .retrieval_reference_number("41277045100")
+                .clone(),
+            push_builder(451000)
+                .retrieval_reference_number("4127704510-0")
+                .clone(),
+            push_builder(451000).acquirer_country_code("USA").clone(),
+        ] {
+            assert!(matches!(
+                builder.build(),
+                Err(crate::utils::BuilderError::ValidationViolition(_))
+            ));
+        }
+
+        assert!(matches!(
+            push_builder(451000)
+                .transaction_currency_code("USX")
+                .build(),
+            Err(crate::utils::BuilderError::ValidationViolition(_))
+        ));
+    }
+}
@@ -0,0 +1,561 @@
+//! # Funds Transfer Models
+//!
+//! This module contains the request and response models of the Visa Direct
+//! Funds Transfer API.
+//!
+//! ## Overview
+//!
+//! #### Request Models
+//! - [`PullFundsRequest`]: Pulls funds from the sender's card (Account Funding
+//!   Transaction, AFT).
+//! - [`PushFundsRequest`]: Pushes funds to the recipient's card (Original
+//!   Credit Transaction, OCT).
+//! - [`ReverseFundsRequest`]: Reverses a pull, e.g. when the matching push
+//!   was declined. It refers to the pull through its
+//!   [`OriginalDataElements`].
+//!
+//! #### Response Models
+//! - [`FundsTransferResponse`]: The result of any of the transactions above,
+//!   or of their status lookup.
+//!
+//! ## Transaction Identification
+//!
+//! Visa identifies a transaction by its acquiring BIN, systems trace audit
+//! number (STAN), retrieval reference number (RRN) and local date and time.
+//! The builders check the format of the STAN, the RRN and the acquirer
+//! country code. Keep these values: they are needed to reverse a pull, and to
+//! look up a transaction whose outcome is unknown.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use visa_sdk::api::visa_direct::funds_transfer::models::PushFundsRequestBuilder;
+//! use visa_sdk::api::visa_direct::models::{CardAcceptorAddressBuilder, CardAcceptorBuilder};
+//!
+//! let card_acceptor = CardAcceptorBuilder::default()
+//!     .name("Visa Inc. USA-Foster City")
+//!     .terminal_id("TID-9999")
+//!     .id_code("CA-IDCode-77765")
+//!     .address(
+//!         CardAcceptorAddressBuilder::default()
+//!             .country("USA")
+//!             .state(Some("CA".to_string()))
+//!             .zip_code(Some("94404".to_string()))
+//!             .build()
+//!             .unwrap(),
+//!     )
+//!     .build()
+//!     .unwrap();
+//!
+//! let payload = PushFundsRequestBuilder::default()
+//!     .systems_trace_audit_number(451000)
+//!     .retrieval_reference_number("412770451000")
+//!     .local_transaction_date_time("2024-01-08T10:22:15")
+//!     .acquiring_bin(408999)
+//!     .acquirer_country_code("840")
+//!     .recipient_primary_account_number("4957030420210496")
+//!     .transaction_currency_code("USD")
+//!     .amount("124.05".parse().unwrap())
+//!     .business_application_id("PP")
+//!     .card_acceptor(card_acceptor)
+//!     .build()
+//!     .expect("Failed to build PushFundsRequest");
+//! ```
+//!
+//! ## Additional Information
+//!
+//! For further details, consult the Visa API documentation: [Visa Direct
+//! Funds Transfer](https://developer.visa.com/capabilities/visa_direct/reference#tag/Funds-Transfer-API).
+
+use crate::{
+    api::visa_direct::models::{validate_identification, CardAcceptor},
+    money::{Amount, Currency, CurrencyInput},
+    utils::BuilderResult,
+};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Pull Funds Transaction (Account Funding Transaction) request structure.
+///
+/// Debits the sender's card, typically to fund a matching
+/// [push][PushFundsRequest].
+#[derive(Clone, Debug, Serialize, Deserialize, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+#[serde(rename_all = "camelCase")]
+pub struct PullFundsRequest {
+    /// Systems trace audit number (STAN), up to 6 digits. Unique per
+    /// transaction of the acquirer within a day.
+    ///
+    /// Example: `451001`
+    pub systems_trace_audit_number: u32,
+
+    /// Retrieval reference number (RRN), 12 alphanumeric characters.
+    /// Recommended format: `ydddhhnnnnnn`, the last digit of the year, the day
+    /// of the year, the hour, and the STAN.
+    ///
+    /// Example: `"412770451001"`
+    #[builder(setter(into))]
+    pub retrieval_reference_number: String,
+
+    /// Local date and time of the transaction at the originator, as
+    /// `YYYY-MM-DDThh:mm:ss`.
+    ///
+    /// Example: `"2024-01-08T10:22:15"`
+    #[builder(setter(into))]
+    pub local_transaction_date_time: String,
+
+    /// The BIN under which the originator is registered with Visa Direct.
+    ///
+    /// Example: `408999`
+    pub acquiring_bin: u32,
+
+    /// ISO 3166 numeric country code of the acquirer.
+    ///
+    /// Example: `"840"`
+    #[builder(setter(into))]
+    pub acquirer_country_code: String,
+
+    /// The card number of the sender.
+    ///
+    /// Example: `"4895142232120006"`
+    #[builder(setter(into))]
+    pub sender_primary_account_number: String,
+
+    /// The expiry date of the sender's card, as `YYYY-MM`.
+    ///
+    /// Example: `"2030-10"`
+    #[builder(setter(into))]
+    pub sender_card_expiry_date: String,
+
+    /// ISO 4217 currency of the amount. Unknown codes are rejected when the
+    /// request is built.
+    ///
+    /// Example: `"USD"`
+    #[builder(
+        setter(into),
+        field(
+            ty = "CurrencyInput",
+            build = "self.sender_currency_code.build(\"sender_currency_code\")?"
+        )
+    )]
+    pub sender_currency_code: Currency,
+
+    /// The amount to pull, in the sender currency.
+    ///
+    /// Example: `"124.02"`
+    pub amount: Amount,
+
+    /// The business application identifier, e.g. `"AA"` (account to account)
+    /// or `"PP"` (person to person).
+    ///
+    /// Example: `"AA"`
+    #[builder(setter(into))]
+    pub business_application_id: String,
+
+    /// The card acceptor of the transaction.
+    pub card_acceptor: CardAcceptor,
+
+    /// The merchant category code of the originator, if different from the
+    /// one registered with Visa.
+    ///
+    /// Example: `6012`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_category_code: Option<u16>,
+
+    /// The fee charged by the originator, in the sender currency.
+    ///
+    /// Example: `"1.50"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub surcharge: Option<Amount>,
+
+    /// The foreign exchange fee charged by the originator, in the sender
+    /// currency.
+    ///
+    /// Example: `"0.50"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreign_exchange_fee_transaction: Option<Amount>,
+
+    /// The Cardholder Authentication Verification Value of a 3-D Secure
+    /// authentication, if any.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cavv: Option<String>,
+}
+
+impl PullFundsRequestBuilder {
+    fn validate(&self) -> BuilderResult<()> {
+        validate_identification(
+            self.systems_trace_audit_number,
+            self.retrieval_reference_number.as_deref(),
+            self.acquirer_country_code.as_deref(),
+        )
+    }
+}
+
+/// Push Funds Transaction (Original Credit Transaction) request structure.
+///
+/// Credits the recipient's card.
+#[derive(Clone, Debug, Serialize, Deserialize, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+#[serde(rename_all = "camelCase")]
+pub struct PushFundsRequest {
+    /// Systems trace audit number (STAN), up to 6 digits. Unique per
+    /// transaction of the acquirer within a day.
+    ///
+    /// Example: `451000`
+    pub systems_trace_audit_number: u32,
+
+    /// Retrieval reference number (RRN), 12 alphanumeric characters.
+    ///
+    /// Example: `"412770451000"`
+    #[builder(setter(into))]
+    pub retrieval_reference_number: String,
+
+    /// Local date and time of the transaction at the originator, as
+    /// `YYYY-MM-DDThh:mm:ss`.
+    ///
+    /// Example: `"2024-01-08T10:22:15"`
+    #[builder(setter(into))]
+    pub local_transaction_date_time: String,
+
+    /// The BIN under which the originator is registered with Visa Direct.
+    ///
+    /// Example: `408999`
+    pub acquiring_bin: u32,
+
+    /// ISO 3166 numeric country code of the acquirer.
+    ///
+    /// Example: `"840"`
+    #[builder(setter(into))]
+    pub acquirer_country_code: String,
+
+    /// The card number of the recipient.
+    ///
+    /// Example: `"4957030420210496"`
+    #[builder(setter(into))]
+    pub recipient_primary_account_number: String,
+
+    /// ISO 4217 currency of the amount. Unknown codes are rejected when the
+    /// request is built.
+    ///
+    /// Example: `"USD"`
+    #[builder(
+        setter(into),
+        field(
+            ty = "CurrencyInput",
+            build = "self.transaction_currency_code.build(\"transaction_currency_code\")?"
+        )
+    )]
+    pub transaction_currency_code: Currency,
+
+    /// The amount to push, in the transaction currency.
+    ///
+    /// Example: `"124.05"`
+    pub amount: Amount,
+
+    /// The business application identifier, e.g. `"AA"` (account to account)
+    /// or `"PP"` (person to person).
+    ///
+    /// Example: `"PP"`
+    #[builder(setter(into))]
+    pub business_application_id: String,
+
+    /// The card acceptor of the transaction.
+    pub card_acceptor: CardAcceptor,
+
+    /// The name of the recipient.
+    ///
+    /// Example: `"Rohan"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_name: Option<String>,
+
+    /// The name of the sender. Required for person to person transfers.
+    ///
+    /// Example: `"Mohammed Qasim"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_name: Option<String>,
+
+    /// The account number of the sender, e.g. the card number funding the
+    /// transfer. Required for person to person transfers.
+    ///
+    /// Example: `"4653459515756154"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_account_number: Option<String>,
+
+    /// The address of the sender.
+    ///
+    /// Example: `"901 Metro Center Blvd"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_address: Option<String>,
+
+    /// The city of the sender.
+    ///
+    /// Example: `"Foster City"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_city: Option<String>,
+
+    /// The state code of the sender.
+    ///
+    /// Example: `"CA"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_state_code: Option<String>,
+
+    /// ISO 3166 alpha-3 or numeric country code of the sender.
+    ///
+    /// Example: `"124"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_country_code: Option<String>,
+
+    /// The source of the funds: `"01"` credit card, `"02"` debit card, `"03"`
+    /// prepaid card, `"04"` cash, `"05"` deposit account.
+    ///
+    /// Example: `"05"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_of_funds_code: Option<String>,
+
+    /// The transaction identifier of the pull funding this push, if any.
+    ///
+    /// Example: `381228649430015`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_identifier: Option<u64>,
+
+    /// The merchant category code of the originator, if different from the
+    /// one registered with Visa.
+    ///
+    /// Example: `6012`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_category_code: Option<u16>,
+}
+
+impl PushFundsRequestBuilder {
+    fn validate(&self) -> BuilderResult<()> {
+        validate_identification(
+            self.systems_trace_audit_number,
+            self.retrieval_reference_number.as_deref(),
+            self.acquirer_country_code.as_deref(),
+        )
+    }
+}
+
+/// Reverse Funds Transaction request structure.
+///
+/// Credits back the sender's card for a [pull][PullFundsRequest], e.g. when
+/// the matching push was declined.
+#[derive(Clone, Debug, Serialize, Deserialize, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+#[serde(rename_all = "camelCase")]
+pub struct ReverseFundsRequest {
+    /// Systems trace audit number (STAN) of the reversal, up to 6 digits.
+    ///
+    /// Example: `451050`
+    pub systems_trace_audit_number: u32,
+
+    /// Retrieval reference number (RRN) of the reversal, 12 alphanumeric
+    /// characters.
+    ///
+    /// Example: `"412770451050"`
+    #[builder(setter(into))]
+    pub retrieval_reference_number: String,
+
+    /// Local date and time of the reversal at the originator, as
+    /// `YYYY-MM-DDThh:mm:ss`.
+    ///
+    /// Example: `"2024-01-08T10:32:15"`
+    #[builder(setter(into))]
+    pub local_transaction_date_time: String,
+
+    /// The BIN under which the originator is registered with Visa Direct.
+    ///
+    /// Example: `408999`
+    pub acquiring_bin: u32,
+
+    /// ISO 3166 numeric country code of the acquirer.
+    ///
+    /// Example: `"840"`
+    #[builder(setter(into))]
+    pub acquirer_country_code: String,
+
+    /// The card number of the sender, as in the pull.
+    ///
+    /// Example: `"4895142232120006"`
+    #[builder(setter(into))]
+    pub sender_primary_account_number: String,
+
+    /// The expiry date of the sender's card, as `YYYY-MM`.
+    ///
+    /// Example: `"2030-10"`
+    #[builder(setter(into))]
+    pub sender_card_expiry_date: String,
+
+    /// ISO 4217 currency of the amount. Unknown codes are rejected when the
+    /// request is built.
+    ///
+    /// Example: `"USD"`
+    #[builder(
+        setter(into),
+        field(
+            ty = "CurrencyInput",
+            build = "self.sender_currency_code.build(\"sender_currency_code\")?"
+        )
+    )]
+    pub sender_currency_code: Currency,
+
+    /// The amount to reverse, in the sender currency.
+    ///
+    /// Example: `"124.02"`
+    pub amount: Amount,
+
+    /// The transaction identifier of the pull, as returned by Visa.
+    ///
+    /// Example: `381228649430015`
+    pub transaction_identifier: u64,
+
+    /// The identification of the pull being reversed.
+    pub original_data_elements: OriginalDataElements,
+
+    /// The card acceptor of the transaction.
+    pub card_acceptor: CardAcceptor,
+}
+
+impl ReverseFundsRequestBuilder {
+    fn validate(&self) -> BuilderResult<()> {
+        validate_identification(
+            self.systems_trace_audit_number,
+            self.retrieval_reference_number.as_deref(),
+            self.acquirer_country_code.as_deref(),
+        )
+    }
+}
+
+/// The identification of the transaction a reversal refers to, as sent and
+/// received in the original pull.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError"))]
+#[serde(rename_all = "camelCase")]
+pub struct OriginalDataElements {
+    /// The approval code of the original transaction.
+    ///
+    /// Example: `"20304B"`
+    #[builder(setter(into))]
+    pub approval_code: String,
+
+    /// The STAN of the original transaction.
+    ///
+    /// Example: `451001`
+    pub systems_trace_audit_number: u32,
+
+    /// The transmission date and time of the original transaction, as
+    /// returned by Visa.
+    ///
+    /// Example: `"2024-01-08T10:22:15"`
+    #[builder(setter(into))]
+    pub transmission_date_time: String,
+
+    /// The acquiring BIN of the original transaction.
+    ///
+    /// Example: `408999`
+    pub acquiring_bin: u32,
+}
+
+/// Funds Transfer API response structure.
+///
+/// Visa answers with `200 OK` once the transaction is processed, or with
+/// `202 Accepted` and a [`status_identifier`][Self::status_identifier] when
+/// it is still in progress. In the latter case, look the outcome up with the
+/// matching status method of
+/// [`FundsTransfer`][super::FundsTransfer].
+///
+/// Example response:
+/// ```json
+/// {
+///     "transactionIdentifier": 381228649430015,
+///     "actionCode": "00",
+///     "approvalCode": "20304B",
+///     "responseCode": "5",
+///     "transmissionDateTime": "2024-01-08T10:22:15.000Z",
+///     "cpsAuthorizationCharacteristicsIndicator": "Y",
+///     "feeProgramIndicator": "123"
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FundsTransferResponse {
+    /// Visa's identifier of the transaction. Link a push to its pull, or a
+    /// reversal to its pull, with this value.
+    ///
+    /// Example: `381228649430015`
+    pub transaction_identifier: Option<u64>,
+
+    /// The result of the transaction, `"00"` when approved.
+    ///
+    /// Example: `"00"`
+    pub action_code: Option<String>,
+
+    /// The approval code of an approved transaction.
+    ///
+    /// Example: `"20304B"`
+    pub approval_code: Option<String>,
+
+    /// The source of the response: `"1"` to `"4"` for the issuer, `"5"` for
+    /// Visa.
+    ///
+    /// Example: `"5"`
+    pub response_code: Option<String>,
+
+    /// The date and time the transaction was transmitted to Visa.
+    ///
+    /// Example: `"2024-01-08T10:22:15.000Z"`
+    pub transmission_date_time: Option<String>,
+
+    /// The identifier to look up a transaction still in progress.
+    ///
+    /// Example: `"234234322342343"`
+    pub status_identifier: Option<String>,
+
+    /// The CPS authorization characteristics indicator.
+    ///
+    /// Example: `"Y"`
+    pub cps_authorization_characteristics_indicator: Option<String>,
+
+    /// The fee program indicator.
+    ///
+    /// Example: `"123"`
+    pub fee_program_indicator: Option<String>,
+
+    /// The result of the CAVV verification, for pulls carrying one.
+    ///
+    /// Example: `"8"`
+    pub cavv_result_code: Option<String>,
+
+    /// The merchant category code of the transaction.
+    ///
+    /// Example: `6012`
+    pub merchant_category_code: Option<u16>,
+}
+
+impl FundsTransferResponse {
+    /// Returns `true` if the transaction was approved.
+    pub fn is_approved(&self) -> bool {
+        self.action_code.as_deref() == Some("00")
+    }
+
+    /// Returns `true` if the transaction is still in progress, and its
+    /// outcome must be looked up with its
+    /// [`status_identifier`][Self::status_identifier].
+    pub fn is_pending(&self) -> bool {
+        self.action_code.is_none() && self.status_identifier.is_some()
+    }
+}
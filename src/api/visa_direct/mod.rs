@@ -0,0 +1,43 @@
+//! # Visa Direct API Module
+//!
+//! This module provides access to the Visa Direct APIs, which move funds to
+//! and from Visa cards in near real time.
+//!
+//! ## Overview
+//!
+//! - [`funds_transfer`]: The Funds Transfer API, to pull funds from a sender
+//!   card (Account Funding Transaction), push funds to a recipient card
+//!   (Original Credit Transaction), and reverse a pull.
+//! - [`multi`]: The multi endpoints of the Funds Transfer API, to submit
+//!   batches of transactions and poll their results.
+//! - [`payouts`]: The Account Payouts API, to send cross-border payouts to
+//!   bank accounts and wallets. Requires Message Level Encryption.
+//! - [`query`]: The Transaction Query API, to look transactions up, e.g. to
+//!   reconcile a push which timed out before resubmitting it.
+//! - [`reports`]: The Reports API, to request and download transaction and
+//!   settlement reports, parsed one row at a time.
+//!
+//! The models shared by the Visa Direct APIs, like the [card
+//! acceptor][models::CardAcceptor], live in [`models`].
+//!
+//! ## Documentation
+//!
+//! For more details, consult the Visa API documentation:
+//! - [Visa Direct](https://developer.visa.com/capabilities/visa_direct)
+//! - [Visa Direct API
+//!   Reference](https://developer.visa.com/capabilities/visa_direct/reference)
+
+#[cfg(test)]
+pub(crate) mod fixtures;
+
+pub mod funds_transfer;
+
+pub mod models;
+
+pub mod multi;
+
+pub mod payouts;
+
+pub mod query;
+
+pub mod reports;
@@ -0,0 +1,118 @@
+//! # Visa Direct Models
+//!
+//! Models shared by the Visa Direct APIs.
+//!
+//! - [`CardAcceptor`]: The originator of the transaction, as it appears to the
+//!   card holder.
+//! - [`CardAcceptorAddress`]: The address of the card acceptor.
+
+use crate::utils::{BuilderError, BuilderResult};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// The card acceptor of a Visa Direct transaction: the originator, as it
+/// appears to the card holder.
+///
+/// Example:
+/// ```json
+/// {
+///     "name": "Visa Inc. USA-Foster City",
+///     "terminalId": "TID-9999",
+///     "idCode": "CA-IDCode-77765",
+///     "address": { "country": "USA", "state": "CA", "zipCode": "94404" }
+/// }
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError"))]
+#[serde(rename_all = "camelCase")]
+pub struct CardAcceptor {
+    /// The name of the card acceptor. Up to 25 characters.
+    ///
+    /// Example: `"Visa Inc. USA-Foster City"`
+    #[builder(setter(into))]
+    pub name: String,
+
+    /// The identifier of the terminal at the card acceptor location. Up to 8
+    /// characters.
+    ///
+    /// Example: `"TID-9999"`
+    #[builder(setter(into))]
+    pub terminal_id: String,
+
+    /// The identifier of the card acceptor, assigned by the acquirer. Up to
+    /// 15 characters.
+    ///
+    /// Example: `"CA-IDCode-77765"`
+    #[builder(setter(into))]
+    pub id_code: String,
+
+    /// The address of the card acceptor.
+    pub address: CardAcceptorAddress,
+}
+
+/// The address of a [`CardAcceptor`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError"))]
+#[serde(rename_all = "camelCase")]
+pub struct CardAcceptorAddress {
+    /// ISO 3166 alpha-3 country code.
+    ///
+    /// Example: `"USA"`
+    #[builder(setter(into))]
+    pub country: String,
+
+    /// State or province code. Required for the United States and Canada.
+    ///
+    /// Example: `"CA"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    /// County code. Required for the United States.
+    ///
+    /// Example: `"081"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub county: Option<String>,
+
+    /// Postal code. Required for the United States and Canada.
+    ///
+    /// Example: `"94404"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip_code: Option<String>,
+}
+
+/// Checks the fields identifying a Visa Direct transaction, which Visa uses to
+/// detect duplicates and to look a transaction up:
+///
+/// - the systems trace audit number (STAN) has up to 6 digits;
+/// - the retrieval reference number (RRN) has exactly 12 alphanumeric
+///   characters;
+/// - the acquirer country code is an ISO 3166 numeric code, with 3 digits.
+pub(crate) fn validate_identification(
+    systems_trace_audit_number: Option<u32>,
+    retrieval_reference_number: Option<&str>,
+    acquirer_country_code: Option<&str>,
+) -> BuilderResult<()> {
+    if systems_trace_audit_number.is_some_and(|stan| stan > 999_999) {
+        return Err(BuilderError::ValidationViolition(
+            "systems_trace_audit_number must have at most 6 digits".to_string(),
+        ));
+    }
+    if retrieval_reference_number
+        .is_some_and(|rrn| rrn.len() != 12 || !rrn.bytes().all(|b| b.is_ascii_alphanumeric()))
+    {
+        return Err(BuilderError::ValidationViolition(
+            "retrieval_reference_number must have 12 alphanumeric characters".to_string(),
+        ));
+    }
+    if acquirer_country_code
+        .is_some_and(|code| code.len() != 3 || !code.bytes().all(|b| b.is_ascii_digit()))
+    {
+        return Err(BuilderError::ValidationViolition(
+            "acquirer_country_code must be a 3-digit ISO 3166 numeric code".to_string(),
+        ));
+    }
+    Ok(())
+}
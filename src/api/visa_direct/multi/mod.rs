@@ -0,0 +1,842 @@
+//! # Multi Funds Transfer API Module
+//!
+//! This module provides access to the multi (batch) endpoints of the Visa
+//! Direct Funds Transfer API, which accept many pull, push or reverse
+//! transactions per call, and process them asynchronously.
+//!
+//! ## Overview
+//!
+//! [`MultiFundsTransfer::submit`] splits a batch into chunks of at most
+//! [`MAX_BATCH_SIZE`] transactions, submits each chunk, and returns a
+//! [`MultiHandle`]. Awaiting the handle polls the status of every chunk with
+//! an exponential backoff until Visa reports the results, and returns them in
+//! the order of the batch: the result at index `i` is the one of the
+//! transaction at index `i`. If some chunks cannot be polled, the results of
+//! the others are kept in a [`MultiError`], along with the chunks to resume.
+//!
+//! The transactions of a batch must share their acquiring BIN, acquirer
+//! country code and business application identifier, which Visa expects once
+//! per call.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! # use visa_sdk::client::{state::WithoutMessageLevelEncryption, VisaClient};
+//! use visa_sdk::api::visa_direct::funds_transfer::models::PushFundsRequest;
+//! use visa_sdk::api::visa_direct::multi::MultiFundsTransfer;
+//!
+//! # async fn example(
+//! #     client: VisaClient<WithoutMessageLevelEncryption>,
+//! #     payouts: Vec<PushFundsRequest>,
+//! # ) -> Result<(), Box<dyn std::error::Error>> {
+//! let multi = MultiFundsTransfer::new(client);
+//!
+//! let handle = multi.submit(&payouts).await?;
+//! // Persist `handle.chunks()` to resume polling after a restart.
+//! let results = handle.await?;
+//! for (payout, result) in payouts.iter().zip(results) {
+//!     match result {
+//!         Some(response) if response.is_approved() => {}
+//!         _ => println!("{} was not approved", payout.retrieval_reference_number),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Documentation
+//!
+//! For more details, consult the Visa API documentation:
+//! - [Visa Direct Funds Transfer API
+//!   Reference](https://developer.visa.com/capabilities/visa_direct/reference#tag/Funds-Transfer-API)
+
+pub mod models;
+
+#[double]
+use crate::client::VisaClient;
+use crate::{
+    api::{
+        request::json_request,
+        response::parse_response,
+        result::{Error, Result},
+        visa_direct::funds_transfer::models::FundsTransferResponse,
+    },
+    client::{
+        models::{RequestOptions, RetryPolicy, RetryPolicyBuilder},
+        retry,
+        utils::MLETrait,
+    },
+};
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use mockall_double::double;
+use models::*;
+use reqwest::{header, Method, Request, StatusCode};
+use std::time::Duration;
+use url::Url;
+
+/// The maximum number of transactions Visa accepts in a multi request.
+pub const MAX_BATCH_SIZE: usize = 1000;
+
+/// [`MultiFundsTransfer`] struct provides methods to submit batches of Visa
+/// Direct transactions.
+///
+/// As for [`FundsTransfer`][super::funds_transfer::FundsTransfer], the
+/// submissions are not retried by the client unless the [`RequestOptions`]
+/// say so.
+///
+/// # Example
+///
+/// ```no_run
+/// # use visa_sdk::client::{state::WithoutMessageLevelEncryption, VisaClient};
+/// use std::time::Duration;
+/// use visa_sdk::api::visa_direct::multi::MultiFundsTransfer;
+/// use visa_sdk::client::models::RetryPolicyBuilder;
+///
+/// # fn example(client: VisaClient<WithoutMessageLevelEncryption>) {
+/// let multi = MultiFundsTransfer::new(client)
+///     .with_max_batch_size(200)
+///     .with_poll_policy(
+///         RetryPolicyBuilder::default()
+///             .max_retries(60u32)
+///             .initial_backoff(Duration::from_secs(1))
+///             .max_backoff(Duration::from_secs(30))
+///             .build()
+///             .unwrap(),
+///     );
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MultiFundsTransfer<MLE>
+where
+    MLE: MLETrait,
+{
+    client: VisaClient<MLE>,
+    url: Url,
+    options: RequestOptions,
+    poll_policy: RetryPolicy,
+    max_batch_size: usize,
+}
+
+impl<MLE> MultiFundsTransfer<MLE>
+where
+    MLE: MLETrait,
+{
+    const URL: &'static str = "visadirect/fundstransfer/v1/";
+
+    /// Creates a new instance of `MultiFundsTransfer`.
+    ///
+    /// The results are polled up to 30 times, with a backoff from 1s up to
+    /// 30s, see [`Self::with_poll_policy`].
+    ///
+    /// # Arguments
+    ///
+    /// - `client`: A `VisaClient` instance. Does not need to have Message Level
+    ///   Encryption enabled.
+    pub fn new(client: VisaClient<MLE>) -> Self {
+        let url = client.get_base_url().join(Self::URL).unwrap();
+        MultiFundsTransfer {
+            client,
+            url,
+            options: RequestOptions::default(),
+            poll_policy: RetryPolicyBuilder::default()
+                .max_retries(30u32)
+                .initial_backoff(Duration::from_secs(1))
+                .max_backoff(Duration::from_secs(30))
+                .build()
+                .expect("the default poll policy is valid"),
+            max_batch_size: MAX_BATCH_SIZE,
+        }
+    }
+
+    /// Sets the [`RequestOptions`] of every call. A correlation ID set here is
+    /// shared by all the calls.
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets how the results are polled: the maximum number of polls after the
+    /// first one, and the backoff between them. A `Retry-After` sent by Visa
+    /// takes precedence over the backoff, capped to its maximum.
+    pub fn with_poll_policy(mut self, poll_policy: RetryPolicy) -> Self {
+        self.poll_policy = poll_policy;
+        self
+    }
+
+    /// Sets the maximum number of transactions per multi request, e.g. when
+    /// the program is provisioned with a lower limit. Clamped to
+    /// `1..=MAX_BATCH_SIZE`.
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size.clamp(1, MAX_BATCH_SIZE);
+        self
+    }
+
+    /// Submits a batch of transactions, in chunks of at most the maximum
+    /// batch size.
+    ///
+    /// Fails without sending anything if the transactions do not share their
+    /// acquiring BIN, acquirer country code and business application
+    /// identifier. If a chunk fails after others were accepted, fails with
+    /// [`MultiError::PartiallySubmitted`], which carries the accepted chunks.
+    ///
+    /// # Arguments
+    ///
+    /// - `transactions`: The [`PullFundsRequest`][crate::api::visa_direct::funds_transfer::models::PullFundsRequest]s,
+    ///   [`PushFundsRequest`][crate::api::visa_direct::funds_transfer::models::PushFundsRequest]s
+    ///   or [`ReverseFundsRequest`][crate::api::visa_direct::funds_transfer::models::ReverseFundsRequest]s
+    ///   of the batch.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "multi_funds_transfer.submit",
+            skip_all,
+            fields(
+                method = "POST",
+                path = T::KIND.path(),
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn submit<T>(&self, transactions: &[T]) -> MultiResult<MultiHandle<'_, MLE>>
+    where
+        T: MultiTransaction,
+    {
+        if let Some(first) = transactions.first() {
+            let header = first.header();
+            if let Some(index) = transactions
+                .iter()
+                .position(|transaction| !transaction.header().is_compatible(&header))
+            {
+                return Err(Error::custom(format!(
+                    "transaction {index} does not share the acquiring BIN, acquirer country code \
+                     and business application identifier of transaction 0"
+                ))
+                .into());
+            }
+        }
+
+        let url = self.url.join(T::KIND.resource()).unwrap();
+        let mut chunks = Vec::new();
+        for (number, batch) in transactions.chunks(self.max_batch_size).enumerate() {
+            let payload = MultiRequest {
+                header: batch[0].header(),
+                request: batch,
+            };
+            match self.submit_chunk(url.clone(), &payload).await {
+                Ok(status_identifier) => chunks.push(MultiChunk {
+                    offset: number * self.max_batch_size,
+                    status_identifier,
+                    transactions: batch.iter().map(T::reference).collect(),
+                }),
+                Err(error) if chunks.is_empty() => return Err(error.into()),
+                Err(error) => {
+                    return Err(MultiError::PartiallySubmitted {
+                        kind: T::KIND,
+                        submitted: chunks,
+                        error,
+                    })
+                }
+            }
+        }
+
+        Ok(MultiHandle {
+            api: self,
+            kind: T::KIND,
+            chunks,
+        })
+    }
+
+    /// Returns a handle on chunks submitted earlier, e.g. to resume polling
+    /// after a restart.
+    pub fn resume(&self, kind: TransactionKind, chunks: Vec<MultiChunk>) -> MultiHandle<'_, MLE> {
+        MultiHandle {
+            api: self,
+            kind,
+            chunks,
+        }
+    }
+
+    async fn submit_chunk<T>(&self, url: Url, payload: &MultiRequest<'_, T>) -> Result<String>
+    where
+        T: MultiTransaction,
+    {
+        let request = json_request(Method::POST, url, payload)?;
+        let response = self
+            .client
+            .execute_request_with_options(request, &self.options)
+            .await?;
+        parse_response::<MultiSubmitResponse>(response)
+            .await?
+            .body
+            .status_identifier
+            .ok_or_else(|| Error::custom("Visa did not return a status identifier"))
+    }
+
+    /// Polls the status of `chunk` until Visa reports its results.
+    async fn poll(
+        &self,
+        kind: TransactionKind,
+        chunk: &MultiChunk,
+    ) -> Result<Vec<MultiItemResponse>> {
+        let mut url = self.url.join(kind.resource()).unwrap();
+        url.path_segments_mut()
+            .unwrap()
+            .push(&chunk.status_identifier);
+
+        let mut attempt = 0;
+        loop {
+            let mut request = Request::new(Method::GET, url.clone());
+            request.headers_mut().insert(
+                header::ACCEPT,
+                header::HeaderValue::from_static("application/json"),
+            );
+            let response = self
+                .client
+                .execute_request_with_options(request, &self.options)
+                .await?;
+
+            let retry_after = retry::retry_after(response.headers());
+            if response.status() != StatusCode::ACCEPTED {
+                let status = parse_response::<MultiStatusResponse>(response).await?;
+                if let Some(items) = status.body.response {
+                    return Ok(items);
+                }
+            }
+
+            if attempt >= self.poll_policy.max_retries {
+                return Err(Error::custom(format!(
+                    "multi {kind} request {} still in progress after {} polls",
+                    chunk.status_identifier,
+                    attempt + 1
+                )));
+            }
+            let delay = match retry_after {
+                Some(retry_after) => retry_after.min(self.poll_policy.max_backoff),
+                None => retry::backoff(&self.poll_policy, attempt),
+            };
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// A batch submitted with [`MultiFundsTransfer::submit`].
+///
+/// Await it, or call [`MultiHandle::wait`], to poll the results. They are in
+/// the order of the batch, and `None` for the transactions Visa did not
+/// report a result for.
+///
+/// Every chunk is polled, even after one fails. If any fails, the call fails
+/// with [`MultiError::PollFailed`], which carries the results of the other
+/// chunks and the chunks still pending.
+pub struct MultiHandle<'a, MLE>
+where
+    MLE: MLETrait,
+{
+    api: &'a MultiFundsTransfer<MLE>,
+    kind: TransactionKind,
+    chunks: Vec<MultiChunk>,
+}
+
+impl<'a, MLE> MultiHandle<'a, MLE>
+where
+    MLE: MLETrait,
+{
+    /// The type of the transactions of the batch.
+    pub fn kind(&self) -> TransactionKind {
+        self.kind
+    }
+
+    /// The chunks of the batch, as accepted by Visa.
+    pub fn chunks(&self) -> &[MultiChunk] {
+        &self.chunks
+    }
+
+    /// Polls the results of every chunk, see [`MultiHandle`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "multi_funds_transfer.wait",
+            skip_all,
+            fields(
+                method = "GET",
+                path = self.kind.status_path(),
+                api_level = ?self.api.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn wait(self) -> MultiResult<Vec<Option<FundsTransferResponse>>> {
+        let len = self
+            .chunks
+            .iter()
+            .map(|chunk| chunk.indices().end)
+            .max()
+            .unwrap_or_default();
+        let mut results = vec![None; len];
+        let mut pending = Vec::new();
+        let mut first_error = None;
+
+        let MultiHandle { api, kind, chunks } = self;
+        for chunk in chunks {
+            match api.poll(kind, &chunk).await {
+                Ok(items) => {
+                    for (position, item) in items.into_iter().enumerate() {
+                        if let Some(index) = item_index(&chunk, position, &item) {
+                            results[chunk.offset + index] = Some(item.response);
+                        }
+                    }
+                }
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                    pending.push(chunk);
+                }
+            }
+        }
+
+        match first_error {
+            None => Ok(results),
+            Some(error) => Err(MultiError::PollFailed {
+                kind,
+                results,
+                pending,
+                error,
+            }),
+        }
+    }
+}
+
+impl<'a, MLE> IntoFuture for MultiHandle<'a, MLE>
+where
+    MLE: MLETrait + Send + Sync + 'a,
+{
+    type Output = MultiResult<Vec<Option<FundsTransferResponse>>>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.wait())
+    }
+}
+
+/// A [`Result`][core::result::Result] failing with a [`MultiError`].
+pub type MultiResult<T> = core::result::Result<T, MultiError>;
+
+/// The error of [`MultiFundsTransfer::submit`] and [`MultiHandle::wait`].
+///
+/// When part of a batch went through, the error carries what is needed to
+/// go on with [`MultiFundsTransfer::resume`].
+#[derive(Debug)]
+pub enum MultiError {
+    /// Nothing was submitted or polled, e.g. the first chunk was rejected.
+    Request(Error),
+
+    /// A chunk failed after others were accepted. The accepted chunks can
+    /// still be polled, the transactions after them were not submitted.
+    PartiallySubmitted {
+        /// The type of the transactions of the batch.
+        kind: TransactionKind,
+        /// The chunks accepted by Visa.
+        submitted: Vec<MultiChunk>,
+        /// Why the next chunk failed.
+        error: Error,
+    },
+
+    /// Some chunks could not be polled. They can be polled again.
+    PollFailed {
+        /// The type of the transactions of the batch.
+        kind: TransactionKind,
+        /// The results of the polled chunks, in the order of the batch, and
+        /// `None` for the transactions of the pending chunks.
+        results: Vec<Option<FundsTransferResponse>>,
+        /// The chunks which could not be polled.
+        pending: Vec<MultiChunk>,
+        /// Why the first pending chunk could not be polled.
+        error: Error,
+    },
+}
+
+impl From<Error> for MultiError {
+    fn from(val: Error) -> Self {
+        Self::Request(val)
+    }
+}
+
+impl core::fmt::Display for MultiError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            MultiError::Request(error) => write!(fmt, "{error}"),
+            MultiError::PartiallySubmitted {
+                kind,
+                submitted,
+                error,
+            } => write!(
+                fmt,
+                "multi {kind} request failed after {} accepted chunks: {error}",
+                submitted.len()
+            ),
+            MultiError::PollFailed {
+                kind,
+                pending,
+                error,
+                ..
+            } => write!(
+                fmt,
+                "multi {kind} request has {} chunks pending: {error}",
+                pending.len()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MultiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MultiError::Request(error)
+            | MultiError::PartiallySubmitted { error, .. }
+            | MultiError::PollFailed { error, .. } => Some(error),
+        }
+    }
+}
+
+/// The index, in `chunk`, of the transaction of the result at `position`.
+///
+/// Results carrying a STAN or an RRN are matched on them. Others are assumed
+/// to be in the order of the chunk.
+fn item_index(chunk: &MultiChunk, position: usize, item: &MultiItemResponse) -> Option<usize> {
+    if item.systems_trace_audit_number.is_none() && item.retrieval_reference_number.is_none() {
+        return (position < chunk.transactions.len()).then_some(position);
+    }
+    chunk.transactions.iter().position(|reference| {
+        item.systems_trace_audit_number
+            .is_none_or(|stan| stan == reference.systems_trace_audit_number)
+            && item
+                .retrieval_reference_number
+                .as_ref()
+                .is_none_or(|rrn| *rrn == reference.retrieval_reference_number)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::visa_direct::{
+        fixtures::push_builder, funds_transfer::models::PushFundsRequest,
+    };
+    use crate::client::models::{ApiLevel, Config};
+    use http::response::Builder as ResponseBuilder;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    const MOCK_URL: &str = "https://domain.test";
+    const MULTI_PUSH_URL: &str =
+        "https://domain.test/visadirect/fundstransfer/v1/multipushfundstransactions";
+
+    fn setup_mock_get_config(mock_client: &mut VisaClient<()>) {
+        mock_client.expect_get_config().return_const(Config {
+            api_level: ApiLevel::Sandbox,
+            ..Default::default()
+        });
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse(MOCK_URL).unwrap());
+    }
+
+    fn response(status: u16, body: &str) -> reqwest::Response {
+        ResponseBuilder::new()
+            .status(status)
+            .body(body.to_string())
+            .unwrap()
+            .into()
+    }
+
+    fn push(stan: u32, acquiring_bin: u32) -> PushFundsRequest {
+        push_builder(stan)
+            .acquiring_bin(acquiring_bin)
+            .build()
+            .unwrap()
+    }
+
+    fn fast_polls(multi: MultiFundsTransfer<()>, max_retries: u32) -> MultiFundsTransfer<()> {
+        multi.with_poll_policy(
+            RetryPolicyBuilder::default()
+                .max_retries(max_retries)
+                .initial_backoff(Duration::from_millis(1))
+                .max_backoff(Duration::from_millis(2))
+                .build()
+                .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_multi_submit_splits_batch() {
+        let bodies = Arc::new(Mutex::new(Vec::new()));
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_get_config(&mut mock_client);
+        let captured = bodies.clone();
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|request, _| {
+                request.method() == Method::POST && request.url().as_str() == MULTI_PUSH_URL
+            })
+            .times(3)
+            .returning(move |request, _| {
+                let body = request.body().and_then(|body| body.as_bytes()).unwrap();
+                let body: serde_json::Value = serde_json::from_slice(body).unwrap();
+                let mut bodies = captured.lock().unwrap();
+                bodies.push(body);
+                Ok(response(
+                    202,
+                    &format!(r#"{{"statusIdentifier": "status-{}"}}"#, bodies.len()),
+                ))
+            });
+
+        let multi = MultiFundsTransfer::new(mock_client).with_max_batch_size(2);
+        let batch: Vec<_> = (1..=5).map(|stan| push(stan, 408999)).collect();
+        let handle = multi.submit(&batch).await.expect("Failed to submit");
+
+        assert_eq!(handle.kind(), TransactionKind::Push);
+        let chunks: Vec<_> = handle
+            .chunks()
+            .iter()
+            .map(|chunk| (chunk.status_identifier.as_str(), chunk.indices()))
+            .collect();
+        assert_eq!(
+            chunks,
+            [("status-1", 0..2), ("status-2", 2..4), ("status-3", 4..5)]
+        );
+
+        let bodies = bodies.lock().unwrap();
+        assert_eq!(bodies[0]["acquiringBin"], 408999);
+        assert_eq!(bodies[0]["acquirerCountryCode"], "840");
+        assert_eq!(bodies[0]["businessApplicationId"], "PP");
+        assert_eq!(bodies[0]["request"].as_array().unwrap().len(), 2);
+        assert_eq!(bodies[2]["request"][0]["systemsTraceAuditNumber"], 5);
+    }
+
+    #[tokio::test]
+    async fn test_multi_submit_rejects_mixed_headers() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_get_config(&mut mock_client);
+        mock_client.expect_execute_request_with_options().never();
+
+        let multi = MultiFundsTransfer::new(mock_client);
+        let batch = [push(1, 408999), push(2, 408999), push(3, 400171)];
+
+        assert!(matches!(
+            multi.submit(&batch).await,
+            Err(MultiError::Request(Error::Custom(message))) if message.starts_with("transaction 2 ")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_multi_submit_partial_failure() {
+        let calls = AtomicUsize::new(0);
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_get_config(&mut mock_client);
+        mock_client
+            .expect_execute_request_with_options()
+            .returning(move |_, _| match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Ok(response(202, r#"{"statusIdentifier": "status-1"}"#)),
+                _ => Ok(response(400, "Bad Request")),
+            });
+
+        let multi = MultiFundsTransfer::new(mock_client).with_max_batch_size(1);
+        let batch = [push(1, 408999), push(2, 408999), push(3, 408999)];
+
+        let Err(MultiError::PartiallySubmitted {
+            kind,
+            submitted,
+            error,
+        }) = multi.submit(&batch).await
+        else {
+            panic!("expected PartiallySubmitted");
+        };
+        assert_eq!(kind, TransactionKind::Push);
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].status_identifier, "status-1");
+        assert!(matches!(error, Error::VisaApiError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_multi_await_maps_results_to_indices() {
+        let polls = AtomicUsize::new(0);
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_get_config(&mut mock_client);
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|request, _| {
+                request.method() == Method::GET
+                    && request.url().as_str() == format!("{MULTI_PUSH_URL}/status-1")
+            })
+            .returning(move |_, _| match polls.fetch_add(1, Ordering::SeqCst) {
+                0 => Ok(response(202, "")),
+                1 => Ok(response(200, r#"{"statusIdentifier": "status-1"}"#)),
+                // Out of order, and without a result for the third transaction.
+                _ => Ok(response(
+                    200,
+                    r#"{"response": [
+                        {"systemsTraceAuditNumber": 2, "retrievalReferenceNumber": "412770000002", "actionCode": "05"},
+                        {"systemsTraceAuditNumber": 1, "retrievalReferenceNumber": "412770000001", "actionCode": "00", "transactionIdentifier": 381228649430015}
+                    ]}"#,
+                )),
+            });
+
+        let multi = fast_polls(MultiFundsTransfer::new(mock_client), 5);
+        let batch = [push(1, 408999), push(2, 408999), push(3, 408999)];
+        let handle = multi.resume(
+            TransactionKind::Push,
+            vec![MultiChunk {
+                offset: 0,
+                status_identifier: "status-1".to_string(),
+                transactions: batch.iter().map(MultiTransaction::reference).collect(),
+            }],
+        );
+
+        let results = handle.await.expect("Failed to poll");
+
+        assert_eq!(results.len(), 3);
+        let first = results[0].as_ref().unwrap();
+        assert!(first.is_approved());
+        assert_eq!(first.transaction_identifier, Some(381228649430015));
+        assert_eq!(
+            results[1].as_ref().unwrap().action_code.as_deref(),
+            Some("05")
+        );
+        assert_eq!(results[2], None);
+    }
+
+    #[tokio::test]
+    async fn test_multi_wait_gives_up() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_get_config(&mut mock_client);
+        mock_client
+            .expect_execute_request_with_options()
+            .times(3)
+            .returning(|_, _| Ok(response(202, "")));
+
+        let multi = fast_polls(MultiFundsTransfer::new(mock_client), 2);
+        let handle = multi.resume(
+            TransactionKind::Pull,
+            vec![MultiChunk {
+                offset: 0,
+                status_identifier: "status-1".to_string(),
+                transactions: vec![],
+            }],
+        );
+
+        let Err(MultiError::PollFailed {
+            results,
+            pending,
+            error,
+            ..
+        }) = handle.wait().await
+        else {
+            panic!("expected PollFailed");
+        };
+        assert!(results.is_empty());
+        assert_eq!(pending.len(), 1);
+        assert!(matches!(
+            error,
+            Error::Custom(message) if message.contains("still in progress after 3 polls")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_multi_wait_caps_retry_after() {
+        let polls = AtomicUsize::new(0);
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_get_config(&mut mock_client);
+        mock_client
+            .expect_execute_request_with_options()
+            .times(2)
+            .returning(move |_, _| match polls.fetch_add(1, Ordering::SeqCst) {
+                0 => Ok(ResponseBuilder::new()
+                    .status(202)
+                    .header("retry-after", "3600")
+                    .body(String::new())
+                    .unwrap()
+                    .into()),
+                _ => Ok(response(200, r#"{"response": []}"#)),
+            });
+
+        let multi = fast_polls(MultiFundsTransfer::new(mock_client), 1);
+        let handle = multi.resume(
+            TransactionKind::Push,
+            vec![MultiChunk {
+                offset: 0,
+                status_identifier: "status-1".to_string(),
+                transactions: vec![],
+            }],
+        );
+
+        let results = tokio::time::timeout(Duration::from_secs(5), handle.wait())
+            .await
+            .expect("Retry-After was not capped to max_backoff");
+        assert_eq!(results.unwrap(), vec![]);
+    }
+
+    #[tokio::test]
+    async fn test_multi_wait_keeps_results_of_polled_chunks() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_get_config(&mut mock_client);
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|request, _| request.url().as_str() == format!("{MULTI_PUSH_URL}/status-1"))
+            .times(1)
+            .returning(|_, _| Ok(response(200, r#"{"response": [{"actionCode": "00"}]}"#)));
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|request, _| request.url().as_str() == format!("{MULTI_PUSH_URL}/status-2"))
+            .times(2)
+            .returning(|_, _| Ok(response(202, "")));
+
+        let multi = fast_polls(MultiFundsTransfer::new(mock_client), 1);
+        let batch = [push(1, 408999), push(2, 408999)];
+        let chunk = |number: usize| MultiChunk {
+            offset: number - 1,
+            status_identifier: format!("status-{number}"),
+            transactions: vec![batch[number - 1].reference()],
+        };
+        let handle = multi.resume(TransactionKind::Push, vec![chunk(1), chunk(2)]);
+
+        let Err(MultiError::PollFailed {
+            kind,
+            results,
+            pending,
+            error,
+        }) = handle.wait().await
+        else {
+            panic!("expected PollFailed");
+        };
+        assert_eq!(kind, TransactionKind::Push);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].as_ref().unwrap().is_approved());
+        assert_eq!(results[1], None);
+        assert_eq!(pending, vec![chunk(2)]);
+        assert!(matches!(error, Error::Custom(_)));
+    }
+
+    #[test]
+    fn test_item_index_falls_back_to_position() {
+        let chunk = MultiChunk {
+            offset: 10,
+            status_identifier: "status-1".to_string(),
+            transactions: [push(1, 408999), push(2, 408999)]
+                .iter()
+                .map(MultiTransaction::reference)
+                .collect(),
+        };
+        let item = |stan: Option<u32>| MultiItemResponse {
+            systems_trace_audit_number: stan,
+            retrieval_reference_number: None,
+            response: FundsTransferResponse::default(),
+        };
+
+        assert_eq!(item_index(&chunk, 1, &item(None)), Some(1));
+        assert_eq!(item_index(&chunk, 2, &item(None)), None);
+        assert_eq!(item_index(&chunk, 0, &item(Some(2))), Some(1));
+        assert_eq!(item_index(&chunk, 0, &item(Some(7))), None);
+    }
+}
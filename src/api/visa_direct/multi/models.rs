@@ -0,0 +1,229 @@
+//! # Multi Funds Transfer Models
+//!
+//! This module contains the models of the Visa Direct multi (batch) Funds
+//! Transfer endpoints.
+//!
+//! The transactions of a batch are the single transaction requests of
+//! [`funds_transfer`][crate::api::visa_direct::funds_transfer]:
+//! [`PullFundsRequest`], [`PushFundsRequest`] and [`ReverseFundsRequest`],
+//! see [`MultiTransaction`]. The fields Visa expects once per batch, like the
+//! acquiring BIN, are taken from the transactions.
+//!
+//! - [`TransactionKind`]: Whether a batch pulls, pushes, or reverses funds.
+//! - [`MultiChunk`]: A part of a batch accepted by Visa, and the status
+//!   identifier to look its results up with. Persist the chunks to resume
+//!   polling, e.g. after a restart.
+//! - [`TransactionReference`]: The STAN and RRN of a transaction, used to map
+//!   the results back to the transactions.
+
+use crate::api::visa_direct::funds_transfer::models::{
+    FundsTransferResponse, PullFundsRequest, PushFundsRequest, ReverseFundsRequest,
+};
+use serde::{Deserialize, Serialize};
+
+/// The type of the transactions of a multi request.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum TransactionKind {
+    /// Pull Funds Transactions (Account Funding Transactions).
+    Pull,
+    /// Push Funds Transactions (Original Credit Transactions).
+    Push,
+    /// Reverse Funds Transactions.
+    Reverse,
+}
+
+impl TransactionKind {
+    /// The resource of the multi endpoint.
+    pub(crate) fn resource(&self) -> &'static str {
+        match self {
+            TransactionKind::Pull => "multipullfundstransactions",
+            TransactionKind::Push => "multipushfundstransactions",
+            TransactionKind::Reverse => "multireversefundstransactions",
+        }
+    }
+
+    /// The path template of the multi endpoint, recorded in the spans.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn path(&self) -> &'static str {
+        match self {
+            TransactionKind::Pull => "/visadirect/fundstransfer/v1/multipullfundstransactions",
+            TransactionKind::Push => "/visadirect/fundstransfer/v1/multipushfundstransactions",
+            TransactionKind::Reverse => {
+                "/visadirect/fundstransfer/v1/multireversefundstransactions"
+            }
+        }
+    }
+
+    /// The path template of the status endpoint, recorded in the spans.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn status_path(&self) -> &'static str {
+        match self {
+            TransactionKind::Pull => {
+                "/visadirect/fundstransfer/v1/multipullfundstransactions/{statusIdentifier}"
+            }
+            TransactionKind::Push => {
+                "/visadirect/fundstransfer/v1/multipushfundstransactions/{statusIdentifier}"
+            }
+            TransactionKind::Reverse => {
+                "/visadirect/fundstransfer/v1/multireversefundstransactions/{statusIdentifier}"
+            }
+        }
+    }
+}
+
+impl core::fmt::Display for TransactionKind {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt.write_str(match self {
+            TransactionKind::Pull => "pull",
+            TransactionKind::Push => "push",
+            TransactionKind::Reverse => "reverse",
+        })
+    }
+}
+
+/// The STAN and RRN identifying a transaction of a batch.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct TransactionReference {
+    /// The systems trace audit number of the transaction.
+    pub systems_trace_audit_number: u32,
+
+    /// The retrieval reference number of the transaction.
+    pub retrieval_reference_number: String,
+}
+
+/// A part of a batch, submitted in one multi request and accepted by Visa.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct MultiChunk {
+    /// The index, in the batch, of the first transaction of the chunk.
+    pub offset: usize,
+
+    /// The status identifier returned by Visa, to look the results up.
+    pub status_identifier: String,
+
+    /// The transactions of the chunk, in order.
+    pub transactions: Vec<TransactionReference>,
+}
+
+impl MultiChunk {
+    /// The indices, in the batch, of the transactions of the chunk.
+    pub fn indices(&self) -> core::ops::Range<usize> {
+        self.offset..self.offset + self.transactions.len()
+    }
+}
+
+/// A transaction which can be submitted in a multi request:
+/// [`PullFundsRequest`], [`PushFundsRequest`] or [`ReverseFundsRequest`].
+///
+/// This trait is sealed.
+pub trait MultiTransaction: Serialize + private::Sealed {
+    /// The type of the transaction.
+    const KIND: TransactionKind;
+
+    #[doc(hidden)]
+    fn header(&self) -> MultiHeader;
+
+    #[doc(hidden)]
+    fn reference(&self) -> TransactionReference;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_multi_transaction {
+    ($request:ty, $kind:expr, $business_application_id:expr) => {
+        impl private::Sealed for $request {}
+
+        impl MultiTransaction for $request {
+            const KIND: TransactionKind = $kind;
+
+            fn header(&self) -> MultiHeader {
+                MultiHeader {
+                    acquiring_bin: self.acquiring_bin,
+                    acquirer_country_code: self.acquirer_country_code.clone(),
+                    business_application_id: $business_application_id(self),
+                    local_transaction_date_time: self.local_transaction_date_time.clone(),
+                }
+            }
+
+            fn reference(&self) -> TransactionReference {
+                TransactionReference {
+                    systems_trace_audit_number: self.systems_trace_audit_number,
+                    retrieval_reference_number: self.retrieval_reference_number.clone(),
+                }
+            }
+        }
+    };
+}
+
+impl_multi_transaction!(
+    PullFundsRequest,
+    TransactionKind::Pull,
+    |request: &PullFundsRequest| Some(request.business_application_id.clone())
+);
+impl_multi_transaction!(
+    PushFundsRequest,
+    TransactionKind::Push,
+    |request: &PushFundsRequest| Some(request.business_application_id.clone())
+);
+impl_multi_transaction!(
+    ReverseFundsRequest,
+    TransactionKind::Reverse,
+    |_: &ReverseFundsRequest| None
+);
+
+/// The fields sent once per multi request.
+#[doc(hidden)]
+#[derive(Clone, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiHeader {
+    pub(crate) acquiring_bin: u32,
+    pub(crate) acquirer_country_code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) business_application_id: Option<String>,
+    pub(crate) local_transaction_date_time: String,
+}
+
+impl MultiHeader {
+    /// Whether transactions with these headers can share a multi request. The
+    /// local date and time may differ, the one of the first transaction is
+    /// sent.
+    pub(crate) fn is_compatible(&self, other: &MultiHeader) -> bool {
+        self.acquiring_bin == other.acquiring_bin
+            && self.acquirer_country_code == other.acquirer_country_code
+            && self.business_application_id == other.business_application_id
+    }
+}
+
+/// A multi request: the header, and the transactions.
+#[derive(Serialize)]
+pub(crate) struct MultiRequest<'a, T> {
+    #[serde(flatten)]
+    pub(crate) header: MultiHeader,
+    pub(crate) request: &'a [T],
+}
+
+/// The answer to a multi request.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MultiSubmitResponse {
+    pub(crate) status_identifier: Option<String>,
+}
+
+/// The answer to a multi status lookup. Without `response`, the request is
+/// still in progress.
+#[derive(Deserialize)]
+pub(crate) struct MultiStatusResponse {
+    pub(crate) response: Option<Vec<MultiItemResponse>>,
+}
+
+/// The result of a transaction of a multi request.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MultiItemResponse {
+    pub(crate) systems_trace_audit_number: Option<u32>,
+    pub(crate) retrieval_reference_number: Option<String>,
+    #[serde(flatten)]
+    pub(crate) response: FundsTransferResponse,
+}
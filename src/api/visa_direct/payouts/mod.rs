@@ -0,0 +1,597 @@
+//! # Account Payouts API Module
+//!
+//! This module provides access to the Visa Direct Account Payouts API, which
+//! sends cross-border payouts to bank accounts and wallets.
+//!
+//! ## Overview
+//!
+//! The [`Payouts`] struct is the main entry point:
+//!
+//! - [`Payouts::validate`] checks a payout against the rules of its corridor,
+//!   without sending it.
+//! - [`Payouts::send`] sends a payout.
+//! - [`Payouts::query`] fetches the status of a payout.
+//! - [`Payouts::cancel`] cancels a payout not paid out yet.
+//!
+//! The Account Payouts API requires Message Level Encryption, so [`Payouts`]
+//! can only be created from a client in the
+//! [`WithMessageLevelEncryption`] state.
+//!
+//! ## Locking a rate
+//!
+//! A rate quoted by the Foreign Exchange API, with rate product `BANK` or
+//! `WALLET` and `quote_id_required` set, is locked by passing the quote to
+//! [`PayoutTransactionDetailBuilder::quote`][models::PayoutTransactionDetailBuilder::quote].
+//!
+//! ## Example
+//!
+//! ```no_run
+//! # use visa_sdk::client::{state::WithMessageLevelEncryption, VisaClient};
+//! use visa_sdk::api::foreign_exchange::models::FXResponseBankOrWallet;
+//! use visa_sdk::api::visa_direct::payouts::{models::*, Payouts};
+//! use visa_sdk::money::Currency;
+//!
+//! # async fn example(
+//! #     client: VisaClient<WithMessageLevelEncryption>,
+//! #     quote: FXResponseBankOrWallet,
+//! #     sender_detail: SenderDetail,
+//! #     recipient_detail: RecipientDetail,
+//! # ) -> Result<(), Box<dyn std::error::Error>> {
+//! let payouts = Payouts::new(client);
+//! let payload = PayoutRequestBuilder::default()
+//!     .payout_method(PayoutMethod::Bank)
+//!     .sender_detail(sender_detail)
+//!     .recipient_detail(recipient_detail)
+//!     .transaction_detail(
+//!         PayoutTransactionDetailBuilder::default()
+//!             .initiating_party_id(1002)
+//!             .client_reference_id("REF-2024-000123")
+//!             .transaction_amount("100.55".parse()?)
+//!             .transaction_currency_code("USD")
+//!             .destination_currency_code(Some(Currency::GBP))
+//!             .business_application_id("PP")
+//!             .quote(&quote)
+//!             .build()?,
+//!     )
+//!     .build()?;
+//!
+//! let validation = payouts.validate(payload.clone()).await?;
+//! if validation.is_valid() {
+//!     let response = payouts.send(payload).await?;
+//!     println!("{:?}", response.transaction_detail.status);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Documentation
+//!
+//! For more details, consult the Visa API documentation:
+//! - [Visa Direct Account Payouts API
+//!   Reference](https://developer.visa.com/capabilities/visa_direct/reference#tag/Payouts-API)
+
+pub mod models;
+
+#[double]
+use crate::client::VisaClient;
+use crate::{
+    api::{
+        request::json_request,
+        response::{parse_response, VisaResponse},
+        result::Result,
+    },
+    client::{models::RequestOptions, state::WithMessageLevelEncryption},
+};
+use mockall_double::double;
+use models::*;
+use reqwest::{header, Method, Request};
+use serde::{de::DeserializeOwned, Serialize};
+use url::Url;
+
+/// [`Payouts`] struct provides methods to interact with the Visa Direct
+/// Account Payouts API.
+///
+/// The Account Payouts API requires Message Level Encryption, so it can only
+/// be created from a [`VisaClient`] in the [`WithMessageLevelEncryption`]
+/// state. Passing a client without MLE is a compile error.
+///
+/// Sending a payout is not retried by default, as a retried send is a double
+/// payout. Query it by its client reference ID instead, see
+/// [`Payouts::query`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use visa_sdk::client::{state::WithMessageLevelEncryption, VisaClient};
+/// use visa_sdk::api::visa_direct::payouts::Payouts;
+///
+/// # fn example(client: VisaClient<WithMessageLevelEncryption>) {
+/// let payouts = Payouts::new(client);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Payouts {
+    client: VisaClient<WithMessageLevelEncryption>,
+    url: Url,
+    options: RequestOptions,
+}
+
+impl Payouts {
+    const URL: &'static str = "visapayouts/v3/payouts";
+    const VALIDATE: &'static str = "validate";
+    const CANCEL: &'static str = "cancel";
+
+    /// Creates a new instance of `Payouts`.
+    ///
+    /// # Arguments
+    ///
+    /// - `client`: A `VisaClient` instance with Message Level Encryption
+    ///   enabled.
+    pub fn new(client: VisaClient<WithMessageLevelEncryption>) -> Self {
+        let url = client.get_base_url().join(Self::URL).unwrap();
+        Payouts {
+            client,
+            url,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Sets the [`RequestOptions`] of every call. A correlation ID set here is
+    /// shared by all the calls.
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sends a payout.
+    ///
+    /// # Arguments
+    ///
+    /// - `payload`: An instance of `PayoutRequest`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "payouts.send",
+            skip_all,
+            fields(
+                method = "POST",
+                path = "/visapayouts/v3/payouts",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn send(&self, payload: PayoutRequest) -> Result<VisaResponse<PayoutResponse>> {
+        self.submit(self.url.clone(), &payload).await
+    }
+
+    /// Checks a payout against the rules of its corridor, without sending it.
+    ///
+    /// # Arguments
+    ///
+    /// - `payload`: An instance of `PayoutRequest`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "payouts.validate",
+            skip_all,
+            fields(
+                method = "POST",
+                path = "/visapayouts/v3/payouts/validate",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn validate(
+        &self,
+        payload: PayoutRequest,
+    ) -> Result<VisaResponse<PayoutValidationResponse>> {
+        self.submit(self.resource_url(Self::VALIDATE), &payload)
+            .await
+    }
+
+    /// Fetches the status of a payout.
+    ///
+    /// # Arguments
+    ///
+    /// - `initiating_party_id`: The ID assigned by Visa to the originator.
+    /// - `payout_id`: The transaction ID or the client reference ID of the
+    ///   payout.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "payouts.query",
+            skip_all,
+            fields(
+                method = "GET",
+                path = "/visapayouts/v3/payouts",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn query(
+        &self,
+        initiating_party_id: i64,
+        payout_id: &PayoutId,
+    ) -> Result<VisaResponse<PayoutResponse>> {
+        let mut url = self.url.clone();
+        url.query_pairs_mut()
+            .append_pair("initiatingPartyId", &initiating_party_id.to_string())
+            .extend_pairs([payout_id.query_pair()]);
+
+        let mut request = Request::new(Method::GET, url);
+        request.headers_mut().insert(
+            header::ACCEPT,
+            header::HeaderValue::from_static("application/json"),
+        );
+        let response = self
+            .client
+            .execute_request_with_options(request, &self.options)
+            .await?;
+        parse_response::<PayoutResponse>(response).await
+    }
+
+    /// Cancels a payout not paid out yet.
+    ///
+    /// # Arguments
+    ///
+    /// - `payload`: An instance of `CancelPayoutRequest`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "payouts.cancel",
+            skip_all,
+            fields(
+                method = "POST",
+                path = "/visapayouts/v3/payouts/cancel",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn cancel(
+        &self,
+        payload: CancelPayoutRequest,
+    ) -> Result<VisaResponse<PayoutResponse>> {
+        self.submit(self.resource_url(Self::CANCEL), &payload).await
+    }
+
+    fn resource_url(&self, resource: &str) -> Url {
+        let mut url = self.url.clone();
+        url.path_segments_mut().unwrap().push(resource);
+        url
+    }
+
+    async fn submit<P, T>(&self, url: Url, payload: &P) -> Result<VisaResponse<T>>
+    where
+        P: Serialize,
+        T: DeserializeOwned,
+    {
+        let request = json_request(Method::POST, url, payload)?;
+        let response = self
+            .client
+            .execute_request_with_options(request, &self.options)
+            .await?;
+        parse_response::<T>(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::foreign_exchange::models::FXResponseBankOrWallet;
+    use crate::client::models::{ApiLevel, Config};
+    use crate::money::{Amount, Currency, Rate};
+    use crate::utils::BuilderError;
+    use http::response::Builder as ResponseBuilder;
+    use serde_json::json;
+
+    const MOCK_URL: &str = "https://domain.test";
+
+    fn setup_mock_client(
+        mock_client: &mut VisaClient<WithMessageLevelEncryption>,
+        method: Method,
+        url: &str,
+        expected_body: Option<serde_json::Value>,
+        body: &str,
+    ) {
+        let response = ResponseBuilder::new()
+            .status(200)
+            .body(body.to_string())
+            .unwrap();
+
+        let url_clone = url.to_string();
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(move |request, _| {
+                let body_matches = match &expected_body {
+                    Some(expected_body) => {
+                        let body: serde_json::Value =
+                            serde_json::from_slice(request.body().unwrap().as_bytes().unwrap())
+                                .unwrap();
+                        request.headers()["content-type"] == "application/json"
+                            && &body == expected_body
+                    }
+                    None => request.body().is_none(),
+                };
+                request.method() == method
+                    && request.url().as_str() == url_clone
+                    && request.headers()["accept"] == "application/json"
+                    && body_matches
+            })
+            .times(1)
+            .returning(move |_, _| Ok(response.clone().into()));
+        mock_client.expect_get_config().return_const(Config {
+            api_level: ApiLevel::Sandbox,
+            ..Default::default()
+        });
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse(MOCK_URL).unwrap());
+    }
+
+    fn sender_detail() -> SenderDetail {
+        SenderDetailBuilder::default()
+            .party_type(PartyType::Individual)
+            .first_name(Some("Alex".to_string()))
+            .last_name(Some("Miller".to_string()))
+            .address(
+                PayoutAddressBuilder::default()
+                    .country("USA")
+                    .city(Some("San Francisco".to_string()))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+
+    fn recipient_detail() -> RecipientDetailBuilder {
+        let mut builder = RecipientDetailBuilder::default();
+        builder
+            .party_type(PartyType::Company)
+            .name(Some("Acme Imports Ltd".to_string()))
+            .bank(Some(
+                RecipientBankBuilder::default()
+                    .account_number("GB29NWBK60161331926819")
+                    .account_number_type(Some("IBAN".to_string()))
+                    .bic(Some("NWBKGB2L".to_string()))
+                    .country_code("GBR")
+                    .currency_code("GBP")
+                    .build()
+                    .unwrap(),
+            ));
+        builder
+    }
+
+    fn transaction_detail() -> PayoutTransactionDetailBuilder {
+        let mut builder = PayoutTransactionDetailBuilder::default();
+        builder
+            .initiating_party_id(1002)
+            .client_reference_id("REF-2024-000123")
+            .transaction_amount(Amount::new(10055, 2))
+            .transaction_currency_code("USD")
+            .destination_currency_code(Some(Currency::GBP))
+            .business_application_id("PP");
+        builder
+    }
+
+    fn payload() -> PayoutRequest {
+        PayoutRequestBuilder::default()
+            .payout_method(PayoutMethod::Bank)
+            .sender_detail(sender_detail())
+            .recipient_detail(recipient_detail().build().unwrap())
+            .transaction_detail(
+                transaction_detail()
+                    .quote(&FXResponseBankOrWallet {
+                        conversion_rate: Rate::new(754, 3),
+                        source_amount: Some(Amount::new(10055, 2)),
+                        destination_amount: Some(Amount::new(7585, 2)),
+                        quote_id: Some(987654321),
+                        quote_id_expiry_datetime: None,
+                    })
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+
+    const PAYOUT_RESPONSE: &str = r#"{
+        "transactionDetail": {
+            "initiatingPartyId": 1002,
+            "transactionId": 431234567890123,
+            "clientReferenceId": "REF-2024-000123",
+            "status": "PAYMENT_RECEIVED",
+            "transactionAmount": "100.55",
+            "transactionCurrencyCode": "USD",
+            "destinationAmount": "75.85",
+            "destinationCurrencyCode": "GBP",
+            "fxConversionRate": "0.754",
+            "quoteId": 987654321
+        }
+    }"#;
+
+    #[tokio::test]
+    async fn test_payouts_send() {
+        let mut mock_client = VisaClient::<WithMessageLevelEncryption>::new();
+        setup_mock_client(
+            &mut mock_client,
+            Method::POST,
+            "https://domain.test/visapayouts/v3/payouts",
+            Some(json!({
+                "payoutMethod": "B",
+                "senderDetail": {
+                    "type": "I",
+                    "address": { "country": "USA", "city": "San Francisco" },
+                    "firstName": "Alex",
+                    "lastName": "Miller"
+                },
+                "recipientDetail": {
+                    "type": "C",
+                    "name": "Acme Imports Ltd",
+                    "bank": {
+                        "accountNumber": "GB29NWBK60161331926819",
+                        "countryCode": "GBR",
+                        "currencyCode": "GBP",
+                        "accountNumberType": "IBAN",
+                        "bic": "NWBKGB2L"
+                    }
+                },
+                "transactionDetail": {
+                    "initiatingPartyId": 1002,
+                    "clientReferenceId": "REF-2024-000123",
+                    "transactionAmount": "100.55",
+                    "transactionCurrencyCode": "USD",
+                    "businessApplicationId": "PP",
+                    "destinationCurrencyCode": "GBP",
+                    "quoteId": 987654321
+                }
+            })),
+            PAYOUT_RESPONSE,
+        );
+
+        let payouts = Payouts::new(mock_client);
+        let result = payouts.send(payload()).await.unwrap();
+
+        assert_eq!(
+            result.body,
+            PayoutResponse {
+                transaction_detail: PayoutTransactionStatus {
+                    initiating_party_id: Some(1002),
+                    transaction_id: Some(431234567890123),
+                    client_reference_id: Some("REF-2024-000123".to_string()),
+                    status: Some(PayoutStatus::PaymentReceived),
+                    status_reason_code: None,
+                    transaction_amount: Some(Amount::new(10055, 2)),
+                    transaction_currency_code: Some("USD".to_string()),
+                    destination_amount: Some(Amount::new(7585, 2)),
+                    destination_currency_code: Some("GBP".to_string()),
+                    fx_conversion_rate: Some(Rate::new(754, 3)),
+                    quote_id: Some(987654321),
+                }
+            }
+        );
+        assert!(!result.transaction_detail.status.unwrap().is_final());
+    }
+
+    #[tokio::test]
+    async fn test_payouts_validate() {
+        let mut mock_client = VisaClient::<WithMessageLevelEncryption>::new();
+        setup_mock_client(
+            &mut mock_client,
+            Method::POST,
+            "https://domain.test/visapayouts/v3/payouts/validate",
+            Some(serde_json::to_value(payload()).unwrap()),
+            r#"{
+                "validationResultCode": "VALIDATION_FAILED",
+                "errors": [
+                    {
+                        "code": "PA-1004",
+                        "message": "bankCodeType SORT_CODE is required for GBR",
+                        "location": "recipientDetail.bank.bankCodeType"
+                    }
+                ]
+            }"#,
+        );
+
+        let payouts = Payouts::new(mock_client);
+        let result = payouts.validate(payload()).await.unwrap();
+
+        assert!(!result.is_valid());
+        assert_eq!(
+            result.errors[0].location.as_deref(),
+            Some("recipientDetail.bank.bankCodeType")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_payouts_query() {
+        let mut mock_client = VisaClient::<WithMessageLevelEncryption>::new();
+        setup_mock_client(
+            &mut mock_client,
+            Method::GET,
+            "https://domain.test/visapayouts/v3/payouts?initiatingPartyId=1002&clientReferenceId=REF-2024-000123",
+            None,
+            r#"{
+                "transactionDetail": {
+                    "clientReferenceId": "REF-2024-000123",
+                    "status": "SETTLEMENT_PENDING"
+                }
+            }"#,
+        );
+
+        let payouts = Payouts::new(mock_client);
+        let result = payouts
+            .query(
+                1002,
+                &PayoutId::ClientReferenceId("REF-2024-000123".to_string()),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.transaction_detail.status, Some(PayoutStatus::Other));
+    }
+
+    #[tokio::test]
+    async fn test_payouts_cancel() {
+        let mut mock_client = VisaClient::<WithMessageLevelEncryption>::new();
+        setup_mock_client(
+            &mut mock_client,
+            Method::POST,
+            "https://domain.test/visapayouts/v3/payouts/cancel",
+            Some(json!({
+                "initiatingPartyId": 1002,
+                "transactionId": 431234567890123u64,
+                "cancellationReason": "Requested by the sender"
+            })),
+            r#"{
+                "transactionDetail": {
+                    "transactionId": 431234567890123,
+                    "status": "CANCELLED"
+                }
+            }"#,
+        );
+
+        let payouts = Payouts::new(mock_client);
+        let payload = CancelPayoutRequestBuilder::default()
+            .initiating_party_id(1002)
+            .payout_id(PayoutId::TransactionId(431234567890123))
+            .cancellation_reason(Some("Requested by the sender".to_string()))
+            .build()
+            .unwrap();
+        let result = payouts.cancel(payload).await.unwrap();
+
+        assert!(result.transaction_detail.status.unwrap().is_final());
+    }
+
+    #[test]
+    fn test_payout_request_validation() {
+        let error = RecipientDetailBuilder::default()
+            .party_type(PartyType::Individual)
+            .first_name(Some("Priya".to_string()))
+            .build()
+            .unwrap_err();
+        assert!(matches!(error, BuilderError::ValidationViolition(_)));
+
+        let error = PayoutRequestBuilder::default()
+            .payout_method(PayoutMethod::Wallet)
+            .sender_detail(sender_detail())
+            .recipient_detail(recipient_detail().build().unwrap())
+            .transaction_detail(transaction_detail().build().unwrap())
+            .build()
+            .unwrap_err();
+        assert!(matches!(error, BuilderError::ValidationViolition(_)));
+
+        let error = transaction_detail()
+            .destination_currency_code(None)
+            .quote_id(Some(987654321))
+            .build()
+            .unwrap_err();
+        assert!(matches!(error, BuilderError::ValidationViolition(_)));
+
+        let error = transaction_detail()
+            .transaction_amount(Amount::new(100555, 3))
+            .build()
+            .unwrap_err();
+        assert!(matches!(error, BuilderError::ValidationViolition(_)));
+    }
+}
@@ -0,0 +1,788 @@
+//! # Payouts Models
+//!
+//! This module contains the request and response models of the Visa Direct
+//! Account Payouts API, which sends cross-border payouts to bank accounts and
+//! wallets.
+//!
+//! ## Overview
+//!
+//! #### Request Models
+//! - [`PayoutRequest`]: A payout, to send or to validate. It is made of:
+//!   - [`SenderDetail`]: The originator of the funds.
+//!   - [`RecipientDetail`]: The beneficiary, with its [`RecipientBank`]
+//!     account or its [`RecipientWallet`], depending on the
+//!     [`PayoutMethod`].
+//!   - [`PayoutTransactionDetail`]: The amounts, currencies and references.
+//!     A rate locked with the Foreign Exchange API is used by passing its
+//!     quote to [`PayoutTransactionDetailBuilder::quote`].
+//! - [`PayoutId`]: Identifies a payout to query or to cancel.
+//! - [`CancelPayoutRequest`]: Cancels a payout not paid out yet.
+//!
+//! #### Response Models
+//! - [`PayoutResponse`]: The status of a payout.
+//! - [`PayoutValidationResponse`]: The result of a validation.
+//!
+//! ## Corridors
+//!
+//! The details Visa requires depend on the corridor, i.e. the destination
+//! country and currency. The builders check what holds for every corridor,
+//! like the names of an individual or a company, or the account of a bank
+//! payout. Corridor-specific codes, like an IBAN, an IFSC or a CLABE, are set
+//! with [`RecipientBank::account_number_type`] and
+//! [`RecipientBank::bank_code_type`]. Use
+//! [`Payouts::validate`][super::Payouts::validate] to check a payout against
+//! the rules of its corridor before sending it.
+//!
+//! ## Additional Information
+//!
+//! For further details, consult the Visa API documentation: [Visa Direct
+//! Account Payouts](https://developer.visa.com/capabilities/visa_direct/reference#tag/Payouts-API).
+
+use crate::{
+    api::foreign_exchange::models::FXResponseBankOrWallet,
+    money::{Amount, Currency, CurrencyInput, Rate},
+    utils::{BuilderError, BuilderResult},
+};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// How a payout is credited to the recipient.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum PayoutMethod {
+    /// To a bank account, see [`RecipientBank`].
+    #[serde(rename = "B")]
+    Bank,
+    /// To a wallet, see [`RecipientWallet`].
+    #[serde(rename = "W")]
+    Wallet,
+}
+
+/// Whether a sender or a recipient is a person or a company.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum PartyType {
+    /// A person, identified by a first and a last name.
+    #[serde(rename = "I")]
+    Individual,
+    /// A company, identified by a name.
+    #[serde(rename = "C")]
+    Company,
+}
+
+/// Checks that an individual has a first and a last name, and a company a
+/// name.
+fn validate_party(
+    party: &str,
+    party_type: Option<PartyType>,
+    first_name: &Option<Option<String>>,
+    last_name: &Option<Option<String>>,
+    name: &Option<Option<String>>,
+) -> BuilderResult<()> {
+    let is_set = |value: &Option<Option<String>>| matches!(value, Some(Some(_)));
+    match party_type {
+        Some(PartyType::Individual) if !is_set(first_name) || !is_set(last_name) => {
+            Err(BuilderError::ValidationViolition(format!(
+                "{party}: first_name and last_name are required for an individual"
+            )))
+        }
+        Some(PartyType::Company) if !is_set(name) => Err(BuilderError::ValidationViolition(
+            format!("{party}: name is required for a company"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Postal address of a sender or a recipient.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError"))]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutAddress {
+    /// ISO 3166 alpha-3 country code.
+    ///
+    /// Example: `"GBR"`
+    #[builder(setter(into))]
+    pub country: String,
+
+    /// The first line of the street address.
+    ///
+    /// Example: `"1 Main Street"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line1: Option<String>,
+
+    /// The second line of the street address.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line2: Option<String>,
+
+    /// The city.
+    ///
+    /// Example: `"London"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+
+    /// State or province code, required in some corridors.
+    ///
+    /// Example: `"CA"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    /// Postal code, required in some corridors.
+    ///
+    /// Example: `"SW1A 1AA"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+}
+
+/// The originator of a payout.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+#[serde(rename_all = "camelCase")]
+pub struct SenderDetail {
+    /// Whether the sender is a person or a company.
+    #[serde(rename = "type")]
+    pub party_type: PartyType,
+
+    /// The address of the sender.
+    pub address: PayoutAddress,
+
+    /// First name of an individual sender.
+    ///
+    /// Example: `"Alex"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+
+    /// Last name of an individual sender.
+    ///
+    /// Example: `"Miller"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+
+    /// Name of a company sender.
+    ///
+    /// Example: `"Acme Remittances Ltd"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The account the funds originate from, e.g. a wallet or an account
+    /// number at the originator.
+    ///
+    /// Example: `"4653459515756154"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_account_number: Option<String>,
+
+    /// Date of birth of an individual sender, as `YYYY-MM-DD`. Required in
+    /// some corridors.
+    ///
+    /// Example: `"1985-06-15"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_of_birth: Option<String>,
+
+    /// ISO 3166 alpha-3 nationality of an individual sender. Required in some
+    /// corridors.
+    ///
+    /// Example: `"USA"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nationality: Option<String>,
+}
+
+impl SenderDetailBuilder {
+    fn validate(&self) -> BuilderResult<()> {
+        validate_party(
+            "sender",
+            self.party_type,
+            &self.first_name,
+            &self.last_name,
+            &self.name,
+        )
+    }
+}
+
+/// The beneficiary of a payout.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+#[serde(rename_all = "camelCase")]
+pub struct RecipientDetail {
+    /// Whether the recipient is a person or a company.
+    #[serde(rename = "type")]
+    pub party_type: PartyType,
+
+    /// First name of an individual recipient.
+    ///
+    /// Example: `"Priya"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+
+    /// Last name of an individual recipient.
+    ///
+    /// Example: `"Sharma"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+
+    /// Name of a company recipient.
+    ///
+    /// Example: `"Acme Imports Pvt Ltd"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The address of the recipient. Required in some corridors.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<PayoutAddress>,
+
+    /// Phone number of the recipient, in international format. Required for
+    /// some wallets.
+    ///
+    /// Example: `"+919876543210"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_number: Option<String>,
+
+    /// Email address of the recipient.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_email: Option<String>,
+
+    /// The bank account to credit, for [`PayoutMethod::Bank`].
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank: Option<RecipientBank>,
+
+    /// The wallet to credit, for [`PayoutMethod::Wallet`].
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet: Option<RecipientWallet>,
+}
+
+impl RecipientDetailBuilder {
+    fn validate(&self) -> BuilderResult<()> {
+        validate_party(
+            "recipient",
+            self.party_type,
+            &self.first_name,
+            &self.last_name,
+            &self.name,
+        )
+    }
+}
+
+/// The bank account of a recipient.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError"))]
+#[serde(rename_all = "camelCase")]
+pub struct RecipientBank {
+    /// The account number, in the format of
+    /// [`account_number_type`][Self::account_number_type].
+    ///
+    /// Example: `"GB29NWBK60161331926819"`
+    #[builder(setter(into))]
+    pub account_number: String,
+
+    /// ISO 3166 alpha-3 country code of the bank.
+    ///
+    /// Example: `"GBR"`
+    #[builder(setter(into))]
+    pub country_code: String,
+
+    /// ISO 4217 currency of the account. Unknown codes are rejected when the
+    /// request is built.
+    ///
+    /// Example: `"GBP"`
+    #[builder(
+        setter(into),
+        field(
+            ty = "CurrencyInput",
+            build = "self.currency_code.build(\"currency_code\")?"
+        )
+    )]
+    pub currency_code: Currency,
+
+    /// The format of the account number: `"DEFAULT"`, or a corridor-specific
+    /// one like `"IBAN"` or `"CLABE"`.
+    ///
+    /// Example: `"IBAN"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_number_type: Option<String>,
+
+    /// The name on the account, if different from the recipient's.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_name: Option<String>,
+
+    /// The type of account, e.g. `"Checking"` or `"Savings"`. Required in
+    /// some corridors.
+    ///
+    /// Example: `"Savings"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<String>,
+
+    /// The code of the bank, in the format of
+    /// [`bank_code_type`][Self::bank_code_type].
+    ///
+    /// Example: `"HDFC0000001"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_code: Option<String>,
+
+    /// The format of the bank code, e.g. `"IFSC"` (India), `"SORT_CODE"`
+    /// (United Kingdom) or `"ABA"` (United States).
+    ///
+    /// Example: `"IFSC"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_code_type: Option<String>,
+
+    /// The name of the bank.
+    ///
+    /// Example: `"HDFC Bank"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_name: Option<String>,
+
+    /// The code of the branch. Required in some corridors.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch_code: Option<String>,
+
+    /// The BIC (SWIFT code) of the bank.
+    ///
+    /// Example: `"NWBKGB2L"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bic: Option<String>,
+}
+
+/// The wallet of a recipient.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError"))]
+#[serde(rename_all = "camelCase")]
+pub struct RecipientWallet {
+    /// The identifier of the wallet at its operator, often a phone number.
+    ///
+    /// Example: `"254712345678"`
+    #[builder(setter(into))]
+    pub account_number: String,
+
+    /// ISO 3166 alpha-3 country code of the wallet.
+    ///
+    /// Example: `"KEN"`
+    #[builder(setter(into))]
+    pub country_code: String,
+
+    /// ISO 4217 currency of the wallet. Unknown codes are rejected when the
+    /// request is built.
+    ///
+    /// Example: `"KES"`
+    #[builder(
+        setter(into),
+        field(
+            ty = "CurrencyInput",
+            build = "self.currency_code.build(\"currency_code\")?"
+        )
+    )]
+    pub currency_code: Currency,
+
+    /// The operator of the wallet, as listed for the corridor.
+    ///
+    /// Example: `"MPESA"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operator: Option<String>,
+}
+
+/// The amounts, currencies and references of a payout.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutTransactionDetail {
+    /// ID assigned by Visa to identify the originating entity.
+    ///
+    /// Example: `1002`
+    pub initiating_party_id: i64,
+
+    /// The originator's unique reference of the payout. Used to query or
+    /// cancel it.
+    ///
+    /// Example: `"REF-2024-000123"`
+    #[builder(setter(into))]
+    pub client_reference_id: String,
+
+    /// The amount to send, in the transaction currency.
+    ///
+    /// Example: `"100.55"`
+    pub transaction_amount: Amount,
+
+    /// ISO 4217 currency of the amount. Unknown codes are rejected when the
+    /// request is built.
+    ///
+    /// Example: `"USD"`
+    #[builder(
+        setter(into),
+        field(
+            ty = "CurrencyInput",
+            build = "self.transaction_currency_code.build(\"transaction_currency_code\")?"
+        )
+    )]
+    pub transaction_currency_code: Currency,
+
+    /// The business application identifier, e.g. `"PP"` (person to person)
+    /// or `"BB"` (business to business).
+    ///
+    /// Example: `"PP"`
+    #[builder(setter(into))]
+    pub business_application_id: String,
+
+    /// ISO 4217 currency credited to the recipient, if different from the
+    /// transaction currency.
+    ///
+    /// Example: `Currency::GBP`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_currency_code: Option<Currency>,
+
+    /// The quote ID of a rate locked with the Foreign Exchange API, see
+    /// [`PayoutTransactionDetailBuilder::quote`].
+    ///
+    /// Example: `987654321`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote_id: Option<i64>,
+
+    /// The purpose of the payout, as listed for the corridor. Required in
+    /// some corridors.
+    ///
+    /// Example: `"ISFAMILY"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purpose_of_payment: Option<String>,
+
+    /// The payout speed, e.g. `"Standard"` or `"Express"`.
+    ///
+    /// Example: `"Standard"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout_speed: Option<String>,
+
+    /// A message shown on the recipient's statement.
+    ///
+    /// Example: `"Family support"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_narrative: Option<String>,
+}
+
+impl PayoutTransactionDetailBuilder {
+    /// Locks the rate of a Foreign Exchange API quote, with rate product
+    /// `BANK` or `WALLET` and `quote_id_required` set. Leaves the quote ID
+    /// unset if the quote has none.
+    pub fn quote(&mut self, quote: &FXResponseBankOrWallet) -> &mut Self {
+        self.quote_id = Some(quote.quote_id);
+        self
+    }
+
+    fn validate(&self) -> BuilderResult<()> {
+        if let (Some(amount), Ok(currency)) = (
+            self.transaction_amount,
+            self.transaction_currency_code
+                .build("transaction_currency_code"),
+        ) {
+            if !amount.fits_currency(currency) {
+                return Err(BuilderError::ValidationViolition(
+                    "transaction_amount exceeds the minor units of the transaction currency"
+                        .to_string(),
+                ));
+            }
+        }
+        if matches!(self.quote_id, Some(Some(_)))
+            && !matches!(self.destination_currency_code, Some(Some(_)))
+        {
+            return Err(BuilderError::ValidationViolition(
+                "quote_id requires destination_currency_code".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Account Payouts API request structure, to send or to validate a payout.
+///
+/// The builder checks that the recipient has a bank account for
+/// [`PayoutMethod::Bank`], and a wallet for [`PayoutMethod::Wallet`].
+///
+/// For more details, visit the [Visa Direct Account Payouts API
+/// Documentation](https://developer.visa.com/capabilities/visa_direct/reference#tag/Payouts-API).
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutRequest {
+    /// How the payout is credited.
+    pub payout_method: PayoutMethod,
+
+    /// The originator of the funds.
+    pub sender_detail: SenderDetail,
+
+    /// The beneficiary.
+    pub recipient_detail: RecipientDetail,
+
+    /// The amounts, currencies and references.
+    pub transaction_detail: PayoutTransactionDetail,
+}
+
+impl PayoutRequestBuilder {
+    fn validate(&self) -> BuilderResult<()> {
+        let (Some(payout_method), Some(recipient)) = (self.payout_method, &self.recipient_detail)
+        else {
+            // Reported as an uninitialized field by the builder.
+            return Ok(());
+        };
+        match payout_method {
+            PayoutMethod::Bank if recipient.bank.is_none() => {
+                Err(BuilderError::ValidationViolition(
+                    "recipient_detail.bank is required for a bank payout".to_string(),
+                ))
+            }
+            PayoutMethod::Wallet if recipient.wallet.is_none() => {
+                Err(BuilderError::ValidationViolition(
+                    "recipient_detail.wallet is required for a wallet payout".to_string(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Identifies a payout, by Visa's transaction identifier or by the
+/// originator's reference.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum PayoutId {
+    /// Visa's identifier of the payout.
+    TransactionId(u64),
+    /// The [`client_reference_id`][PayoutTransactionDetail::client_reference_id]
+    /// of the payout.
+    ClientReferenceId(String),
+}
+
+impl PayoutId {
+    /// The query parameter of the identifier.
+    pub(crate) fn query_pair(&self) -> (&'static str, String) {
+        match self {
+            PayoutId::TransactionId(transaction_id) => {
+                ("transactionId", transaction_id.to_string())
+            }
+            PayoutId::ClientReferenceId(reference) => ("clientReferenceId", reference.clone()),
+        }
+    }
+}
+
+/// Cancel Payout request structure.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError"))]
+#[serde(rename_all = "camelCase")]
+pub struct CancelPayoutRequest {
+    /// ID assigned by Visa to identify the originating entity.
+    ///
+    /// Example: `1002`
+    pub initiating_party_id: i64,
+
+    /// The payout to cancel.
+    #[serde(flatten)]
+    pub payout_id: PayoutId,
+
+    /// The reason of the cancellation.
+    ///
+    /// Example: `"Requested by the sender"`
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<String>,
+}
+
+/// The status of a payout.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PayoutStatus {
+    /// Visa received the payout.
+    PaymentReceived,
+    /// The payout is being processed.
+    Pending,
+    /// The payout is held, e.g. for a compliance review.
+    Hold,
+    /// The payout was credited to the recipient.
+    Success,
+    /// The payout was cancelled.
+    Cancelled,
+    /// The payout was rejected.
+    Rejected,
+    /// The payout was returned by the recipient's institution.
+    Returned,
+    /// A status unknown to this SDK.
+    #[serde(other)]
+    Other,
+}
+
+impl PayoutStatus {
+    /// Returns `true` if the payout will not change status anymore.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            PayoutStatus::Success
+                | PayoutStatus::Cancelled
+                | PayoutStatus::Rejected
+                | PayoutStatus::Returned
+        )
+    }
+}
+
+/// Account Payouts API response structure.
+///
+/// Example response:
+/// ```json
+/// {
+///     "transactionDetail": {
+///         "initiatingPartyId": 1002,
+///         "transactionId": 431234567890123,
+///         "clientReferenceId": "REF-2024-000123",
+///         "status": "PAYMENT_RECEIVED",
+///         "transactionAmount": "100.55",
+///         "transactionCurrencyCode": "USD",
+///         "destinationAmount": "75.85",
+///         "destinationCurrencyCode": "GBP",
+///         "fxConversionRate": "0.754",
+///         "quoteId": 987654321
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutResponse {
+    /// The status and amounts of the payout.
+    pub transaction_detail: PayoutTransactionStatus,
+}
+
+/// The status and amounts of a payout, see [`PayoutResponse`].
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutTransactionStatus {
+    /// ID assigned by Visa to identify the originating entity.
+    ///
+    /// Example: `1002`
+    pub initiating_party_id: Option<i64>,
+
+    /// Visa's identifier of the payout.
+    ///
+    /// Example: `431234567890123`
+    pub transaction_id: Option<u64>,
+
+    /// The originator's reference of the payout.
+    ///
+    /// Example: `"REF-2024-000123"`
+    pub client_reference_id: Option<String>,
+
+    /// The status of the payout.
+    ///
+    /// Example: `PayoutStatus::PaymentReceived`
+    pub status: Option<PayoutStatus>,
+
+    /// The reason of a rejection, return or hold, if any.
+    ///
+    /// Example: `"RC5001"`
+    pub status_reason_code: Option<String>,
+
+    /// The amount sent, in the transaction currency.
+    ///
+    /// Example: `"100.55"`
+    pub transaction_amount: Option<Amount>,
+
+    /// The transaction currency.
+    ///
+    /// Example: `"USD"`
+    pub transaction_currency_code: Option<String>,
+
+    /// The amount credited, in the destination currency.
+    ///
+    /// Example: `"75.85"`
+    pub destination_amount: Option<Amount>,
+
+    /// The destination currency.
+    ///
+    /// Example: `"GBP"`
+    pub destination_currency_code: Option<String>,
+
+    /// The conversion rate applied.
+    ///
+    /// Example: `"0.754"`
+    pub fx_conversion_rate: Option<Rate>,
+
+    /// The quote ID the rate was locked with, if any.
+    ///
+    /// Example: `987654321`
+    pub quote_id: Option<i64>,
+}
+
+/// Validate Payout response structure.
+///
+/// Example response:
+/// ```json
+/// {
+///     "validationResultCode": "VALIDATION_FAILED",
+///     "errors": [
+///         {
+///             "code": "PA-1004",
+///             "message": "bankCodeType IFSC is required for IND",
+///             "location": "recipientDetail.bank.bankCodeType"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutValidationResponse {
+    /// The result of the validation, e.g. `"VALIDATION_SUCCESSFUL"`.
+    ///
+    /// Example: `"VALIDATION_FAILED"`
+    pub validation_result_code: Option<String>,
+
+    /// The rules of the corridor the payout breaks, if any.
+    #[serde(default)]
+    pub errors: Vec<PayoutValidationError>,
+}
+
+impl PayoutValidationResponse {
+    /// Returns `true` if the payout can be sent as is.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+            && self.validation_result_code.as_deref() != Some("VALIDATION_FAILED")
+    }
+}
+
+/// A rule of the corridor a payout breaks.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct PayoutValidationError {
+    /// Visa's code of the rule.
+    ///
+    /// Example: `"PA-1004"`
+    pub code: Option<String>,
+
+    /// The human-readable description of the rule.
+    ///
+    /// Example: `"bankCodeType IFSC is required for IND"`
+    pub message: Option<String>,
+
+    /// The path of the offending field.
+    ///
+    /// Example: `"recipientDetail.bank.bankCodeType"`
+    pub location: Option<String>,
+}
@@ -0,0 +1,568 @@
+//! # Transaction Query API Module
+//!
+//! This module provides access to the Visa Direct Transaction Query API,
+//! which looks transactions up by their acquiring BIN and retrieval reference
+//! number, STAN or transaction identifier.
+//!
+//! ## Overview
+//!
+//! The [`TransactionQuery`] struct is the main entry point:
+//!
+//! - [`TransactionQuery::query`] fetches a page of results.
+//! - [`TransactionQuery::query_all`] fetches every page.
+//! - [`TransactionQuery::reconcile_push`] finds out the outcome of a push
+//!   whose response was lost, e.g. on a timeout, before deciding to resubmit
+//!   it. Resubmitting a push which Visa did approve is a double payout.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! # use visa_sdk::client::{state::WithoutMessageLevelEncryption, VisaClient};
+//! use visa_sdk::api::visa_direct::funds_transfer::{models::PushFundsRequest, FundsTransfer};
+//! use visa_sdk::api::visa_direct::query::{models::PushReconciliation, TransactionQuery};
+//!
+//! # async fn example(
+//! #     client: VisaClient<WithoutMessageLevelEncryption>,
+//! #     payload: PushFundsRequest,
+//! # ) -> Result<(), Box<dyn std::error::Error>> {
+//! let funds_transfer = FundsTransfer::new(client.clone());
+//! let query = TransactionQuery::new(client);
+//!
+//! if funds_transfer.push(payload.clone()).await.is_err() {
+//!     match query.reconcile_push(&payload).await? {
+//!         PushReconciliation::Approved(record) => println!("paid: {record:?}"),
+//!         PushReconciliation::Pending(record) => println!("in progress: {record:?}"),
+//!         PushReconciliation::Declined(record) => println!("declined: {record:?}"),
+//!         PushReconciliation::NotFound => {
+//!             funds_transfer.push(payload).await?;
+//!         }
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Documentation
+//!
+//! For more details, consult the Visa API documentation:
+//! - [Visa Direct Transaction Query API
+//!   Reference](https://developer.visa.com/capabilities/visa_direct/reference#tag/Transaction-Query-API)
+
+pub mod models;
+
+#[double]
+use crate::client::VisaClient;
+use crate::{
+    api::{
+        response::{parse_response, VisaResponse},
+        result::{Error, Result},
+        visa_direct::funds_transfer::models::PushFundsRequest,
+    },
+    client::{models::RequestOptions, utils::MLETrait},
+};
+use mockall_double::double;
+use models::*;
+use reqwest::{header, Method, Request, StatusCode};
+use url::Url;
+
+/// [`TransactionQuery`] struct provides methods to interact with the Visa
+/// Direct Transaction Query API.
+///
+/// # Example
+///
+/// ```no_run
+/// # use visa_sdk::client::{state::WithoutMessageLevelEncryption, VisaClient};
+/// use visa_sdk::api::visa_direct::query::{models::QueryFilterBuilder, TransactionQuery};
+///
+/// # async fn example(client: VisaClient<WithoutMessageLevelEncryption>) {
+/// let query = TransactionQuery::new(client);
+/// let filter = QueryFilterBuilder::default()
+///     .acquiring_bin(408999)
+///     .transaction_identifier(Some(381228649430015))
+///     .build()
+///     .expect("Failed to build QueryFilter");
+/// let records = query.query_all(&filter).await.expect("Failed to query");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TransactionQuery<MLE>
+where
+    MLE: MLETrait,
+{
+    client: VisaClient<MLE>,
+    url: Url,
+    options: RequestOptions,
+}
+
+impl<MLE> TransactionQuery<MLE>
+where
+    MLE: MLETrait,
+{
+    const URL: &'static str = "visadirect/v1/transactionquery";
+
+    /// The error code of a 404 meaning that no transaction matches the filter.
+    const NO_RECORDS_CODE: &'static str = "3001";
+
+    /// Creates a new instance of `TransactionQuery`.
+    ///
+    /// # Arguments
+    ///
+    /// - `client`: A `VisaClient` instance. Does not need to have Message Level
+    ///   Encryption enabled.
+    pub fn new(client: VisaClient<MLE>) -> Self {
+        let url = client.get_base_url().join(Self::URL).unwrap();
+        TransactionQuery {
+            client,
+            url,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Sets the [`RequestOptions`] of every call. A correlation ID set here is
+    /// shared by all the calls.
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Fetches the page of transactions matching `filter`, starting at the
+    /// `start_index`th one.
+    ///
+    /// # Arguments
+    ///
+    /// - `filter`: An instance of `QueryFilter`.
+    /// - `start_index`: The index of the first transaction of the page,
+    ///   starting at 0.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "transaction_query.query",
+            skip_all,
+            fields(
+                method = "GET",
+                path = "/visadirect/v1/transactionquery",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn query(
+        &self,
+        filter: &QueryFilter,
+        start_index: u32,
+    ) -> Result<VisaResponse<QueryPage>> {
+        let mut url = self.url.clone();
+        url.query_pairs_mut()
+            .extend_pairs(filter.query_pairs(start_index));
+
+        let mut request = Request::new(Method::GET, url);
+        request.headers_mut().insert(
+            header::ACCEPT,
+            header::HeaderValue::from_static("application/json"),
+        );
+        let response = self
+            .client
+            .execute_request_with_options(request, &self.options)
+            .await?;
+        parse_response::<QueryPage>(response).await
+    }
+
+    /// Fetches every page of transactions matching `filter`.
+    ///
+    /// Stops at the first empty page, once `totalCount` transactions were
+    /// fetched, after a page shorter than the page size, or at the first page
+    /// without a new transaction, in case Visa ignores `startIndex`.
+    ///
+    /// # Arguments
+    ///
+    /// - `filter`: An instance of `QueryFilter`.
+    pub async fn query_all(&self, filter: &QueryFilter) -> Result<Vec<TransactionRecord>> {
+        let mut records = Vec::new();
+        loop {
+            let page = self.query(filter, records.len() as u32).await?.body;
+            let fetched = page.transactions.len();
+            // Also true of an empty page.
+            let no_new_records = page
+                .transactions
+                .iter()
+                .all(|transaction| records.contains(transaction));
+            if no_new_records {
+                return Ok(records);
+            }
+            records.extend(page.transactions);
+
+            let done = match page.total_count {
+                Some(total_count) => records.len() >= total_count as usize,
+                None => filter
+                    .page_size
+                    .is_some_and(|page_size| fetched < page_size as usize),
+            };
+            if done {
+                return Ok(records);
+            }
+        }
+    }
+
+    /// Looks up a push whose response was lost, by its acquiring BIN, STAN
+    /// and retrieval reference number.
+    ///
+    /// Only resubmit the push, with the same STAN and RRN, on
+    /// [`PushReconciliation::NotFound`]. A 404 is only read as
+    /// [`PushReconciliation::NotFound`] with Visa's "no records" error code,
+    /// `3001`: any other 404 is returned as an error. Visa may take a few seconds to record
+    /// a transaction: wait at least the timeout of the push before
+    /// reconciling it.
+    ///
+    /// # Arguments
+    ///
+    /// - `payload`: The push, as submitted.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "transaction_query.reconcile_push",
+            skip_all,
+            fields(
+                method = "GET",
+                path = "/visadirect/v1/transactionquery",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn reconcile_push(&self, payload: &PushFundsRequest) -> Result<PushReconciliation> {
+        let filter = QueryFilterBuilder::default()
+            .acquiring_bin(payload.acquiring_bin)
+            .retrieval_reference_number(Some(payload.retrieval_reference_number.clone()))
+            .systems_trace_audit_number(Some(payload.systems_trace_audit_number))
+            .build()
+            .map_err(Error::custom)?;
+
+        let records = match self.query_all(&filter).await {
+            Ok(records) => records,
+            Err(Error::VisaApiError(error))
+                if error.status == StatusCode::NOT_FOUND
+                    && error.code() == Some(Self::NO_RECORDS_CODE) =>
+            {
+                return Ok(PushReconciliation::NotFound)
+            }
+            Err(error) => return Err(error),
+        };
+
+        let matching: Vec<_> = records
+            .into_iter()
+            .filter(|record| {
+                record.retrieval_reference_number.as_deref()
+                    == Some(payload.retrieval_reference_number.as_str())
+                    && record.systems_trace_audit_number == Some(payload.systems_trace_audit_number)
+            })
+            .collect();
+
+        let approved = matching.iter().find(|record| record.is_approved());
+        let pending = matching.iter().find(|record| record.action_code.is_none());
+        Ok(match (approved, pending, matching.first()) {
+            (Some(record), _, _) => PushReconciliation::Approved(record.clone()),
+            (None, Some(record), _) => PushReconciliation::Pending(record.clone()),
+            (None, None, Some(record)) => PushReconciliation::Declined(record.clone()),
+            (None, None, None) => PushReconciliation::NotFound,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::visa_direct::fixtures::push_builder;
+    use crate::client::models::{ApiLevel, Config};
+    use crate::money::Amount;
+    use http::response::Builder as ResponseBuilder;
+
+    const MOCK_URL: &str = "https://domain.test";
+
+    fn setup_mock_execute_request(
+        mock_client: &mut VisaClient<()>,
+        url: &str,
+        status: u16,
+        body: &str,
+    ) {
+        let response = ResponseBuilder::new()
+            .status(status)
+            .body(body.to_string())
+            .unwrap();
+
+        let url_clone = url.to_string();
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(move |request, _| {
+                request.method() == Method::GET
+                    && request.url().as_str() == url_clone
+                    && request.headers()["accept"] == "application/json"
+            })
+            .times(1)
+            .returning(move |_, _| Ok(response.clone().into()));
+    }
+
+    fn setup_mock_get_config(mock_client: &mut VisaClient<()>, api_level: ApiLevel) {
+        mock_client.expect_get_config().return_const(Config {
+            api_level,
+            ..Default::default()
+        });
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse(MOCK_URL).unwrap());
+    }
+
+    fn push_payload() -> PushFundsRequest {
+        push_builder(451000).build().unwrap()
+    }
+
+    const RECONCILE_URL: &str = "https://domain.test/visadirect/v1/transactionquery?acquiringBIN=408999&rrn=412770451000&stan=451000";
+
+    #[tokio::test]
+    async fn test_transaction_query_query() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(
+            &mut mock_client,
+            format!(
+                "{}/visadirect/v1/transactionquery?acquiringBIN=408999&transactionIdentifier=381228649430015&fromDate=2024-01-08&pageSize=50",
+                self::MOCK_URL
+            )
+            .as_str(),
+            200,
+            r#"{
+                "transactions": [{
+                    "transactionIdentifier": 381228649430015,
+                    "acquiringBIN": 408999,
+                    "rrn": "412770451000",
+                    "stan": 451000,
+                    "transactionType": "OCT",
+                    "actionCode": "00",
+                    "approvalCode": "20304B",
+                    "amount": "124.05",
+                    "currencyCode": "USD"
+                }],
+                "totalCount": 1
+            }"#,
+        );
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let query = TransactionQuery::new(mock_client);
+        let filter = QueryFilterBuilder::default()
+            .acquiring_bin(408999)
+            .transaction_identifier(Some(381228649430015))
+            .from_date(Some("2024-01-08".to_string()))
+            .page_size(Some(50))
+            .build()
+            .expect("Failed to build QueryFilter");
+
+        let result = query.query(&filter, 0).await.expect("Failed to query");
+
+        assert_eq!(result.total_count, Some(1));
+        assert_eq!(
+            result.transactions,
+            [TransactionRecord {
+                transaction_identifier: Some(381228649430015),
+                acquiring_bin: Some(408999),
+                retrieval_reference_number: Some("412770451000".to_string()),
+                systems_trace_audit_number: Some(451000),
+                transaction_type: Some("OCT".to_string()),
+                action_code: Some("00".to_string()),
+                approval_code: Some("20304B".to_string()),
+                amount: Some(Amount::new(12405, 2)),
+                currency_code: Some("USD".to_string()),
+                ..Default::default()
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transaction_query_query_all_pages() {
+        let base = format!(
+            "{}/visadirect/v1/transactionquery?acquiringBIN=408999&stan=451000&pageSize=2",
+            self::MOCK_URL
+        );
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(
+            &mut mock_client,
+            &base,
+            200,
+            r#"{"transactions": [{"transactionIdentifier": 1}, {"transactionIdentifier": 2}]}"#,
+        );
+        setup_mock_execute_request(
+            &mut mock_client,
+            &format!("{base}&startIndex=2"),
+            200,
+            r#"{"transactions": [{"transactionIdentifier": 3}]}"#,
+        );
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let query = TransactionQuery::new(mock_client);
+        let filter = QueryFilterBuilder::default()
+            .acquiring_bin(408999)
+            .systems_trace_audit_number(Some(451000))
+            .page_size(Some(2))
+            .build()
+            .unwrap();
+
+        let records = query.query_all(&filter).await.expect("Failed to query");
+
+        let identifiers: Vec<_> = records
+            .iter()
+            .map(|record| record.transaction_identifier.unwrap())
+            .collect();
+        assert_eq!(identifiers, [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_query_query_all_stops_when_start_index_is_ignored() {
+        let base = format!(
+            "{}/visadirect/v1/transactionquery?acquiringBIN=408999&stan=451000",
+            self::MOCK_URL
+        );
+        let page =
+            r#"{"transactions": [{"transactionIdentifier": 1}, {"transactionIdentifier": 2}]}"#;
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(&mut mock_client, &base, 200, page);
+        setup_mock_execute_request(&mut mock_client, &format!("{base}&startIndex=2"), 200, page);
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let query = TransactionQuery::new(mock_client);
+        let filter = QueryFilterBuilder::default()
+            .acquiring_bin(408999)
+            .systems_trace_audit_number(Some(451000))
+            .build()
+            .unwrap();
+
+        let records = query.query_all(&filter).await.expect("Failed to query");
+
+        let identifiers: Vec<_> = records
+            .iter()
+            .map(|record| record.transaction_identifier.unwrap())
+            .collect();
+        assert_eq!(identifiers, [1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_query_reconcile_push_approved() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_execute_request(
+            &mut mock_client,
+            RECONCILE_URL,
+            200,
+            r#"{
+                "transactions": [
+                    {"rrn": "412770451000", "stan": 451000, "actionCode": "05"},
+                    {"rrn": "412770451000", "stan": 451000, "actionCode": "00", "transactionIdentifier": 381228649430015}
+                ],
+                "totalCount": 2
+            }"#,
+        );
+        setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+        let query = TransactionQuery::new(mock_client);
+        let reconciliation = query
+            .reconcile_push(&push_payload())
+            .await
+            .expect("Failed to reconcile");
+
+        let PushReconciliation::Approved(record) = reconciliation else {
+            panic!("expected an approved push, got {reconciliation:?}");
+        };
+        assert_eq!(record.transaction_identifier, Some(381228649430015));
+    }
+
+    #[tokio::test]
+    async fn test_transaction_query_reconcile_push_pending_and_declined() {
+        for (body, expected) in [
+            (
+                r#"{"transactions": [{"rrn": "412770451000", "stan": 451000, "actionCode": "05"}, {"rrn": "412770451000", "stan": 451000}], "totalCount": 2}"#,
+                "Pending",
+            ),
+            (
+                r#"{"transactions": [{"rrn": "412770451000", "stan": 451000, "actionCode": "05"}], "totalCount": 1}"#,
+                "Declined",
+            ),
+        ] {
+            let mut mock_client = VisaClient::<()>::new();
+            setup_mock_execute_request(&mut mock_client, RECONCILE_URL, 200, body);
+            setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+            let query = TransactionQuery::new(mock_client);
+            let reconciliation = query.reconcile_push(&push_payload()).await.unwrap();
+
+            assert!(format!("{reconciliation:?}").starts_with(expected));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transaction_query_reconcile_push_not_found() {
+        for (status, body) in [
+            // Another transaction of the acquirer.
+            (
+                200,
+                r#"{"transactions": [{"rrn": "412770451001", "stan": 451000, "actionCode": "00"}], "totalCount": 1}"#,
+            ),
+            (200, r#"{"transactions": []}"#),
+            (
+                404,
+                r#"{"responseStatus": {"status": 404, "code": "3001", "severity": "ERROR", "message": "No records found", "info": ""}}"#,
+            ),
+        ] {
+            let mut mock_client = VisaClient::<()>::new();
+            setup_mock_execute_request(&mut mock_client, RECONCILE_URL, status, body);
+            setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+            let query = TransactionQuery::new(mock_client);
+
+            assert_eq!(
+                query.reconcile_push(&push_payload()).await.unwrap(),
+                PushReconciliation::NotFound
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transaction_query_reconcile_push_error() {
+        for (status, body) in [
+            (503, "Service Unavailable"),
+            // Not Visa's "no records" code, e.g. a wrong path or a gateway.
+            (
+                404,
+                r#"{"responseStatus": {"status": 404, "code": "9208", "severity": "ERROR", "message": "Resource not found", "info": ""}}"#,
+            ),
+            (404, "Not Found"),
+        ] {
+            let mut mock_client = VisaClient::<()>::new();
+            setup_mock_execute_request(&mut mock_client, RECONCILE_URL, status, body);
+            setup_mock_get_config(&mut mock_client, ApiLevel::Sandbox);
+
+            let query = TransactionQuery::new(mock_client);
+
+            // An unknown outcome is never reported as NotFound.
+            assert!(matches!(
+                query.reconcile_push(&push_payload()).await,
+                Err(Error::VisaApiError(error)) if error.status == status
+            ));
+        }
+    }
+
+    #[test]
+    fn test_query_filter_requires_an_identification() {
+        assert!(matches!(
+            QueryFilterBuilder::default().acquiring_bin(408999).build(),
+            Err(crate::utils::BuilderError::ValidationViolition(_))
+        ));
+        assert!(matches!(
+            QueryFilterBuilder::default()
+                .acquiring_bin(408999)
+                .retrieval_reference_number(Some("4127704510".to_string()))
+                .build(),
+            Err(crate::utils::BuilderError::ValidationViolition(_))
+        ));
+        assert!(matches!(
+            QueryFilterBuilder::default()
+                .acquiring_bin(408999)
+                .systems_trace_audit_number(Some(451000))
+                .page_size(Some(0))
+                .build(),
+            Err(crate::utils::BuilderError::ValidationViolition(_))
+        ));
+    }
+}
@@ -0,0 +1,254 @@
+//! # Transaction Query Models
+//!
+//! This module contains the models of the Visa Direct Transaction Query API.
+//!
+//! - [`QueryFilter`]: The transactions to look up, by acquiring BIN and
+//!   retrieval reference number, STAN or transaction identifier.
+//! - [`QueryPage`]: A page of results.
+//! - [`TransactionRecord`]: A transaction, as known to Visa.
+//! - [`PushReconciliation`]: The outcome of a push whose response was lost,
+//!   see [`TransactionQuery::reconcile_push`][super::TransactionQuery::reconcile_push].
+
+use crate::{
+    api::visa_direct::models::validate_identification,
+    money::Amount,
+    utils::{BuilderError, BuilderResult},
+};
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+/// Transaction Query API filter.
+///
+/// The acquiring BIN is required, along with at least one of the retrieval
+/// reference number, the STAN or the transaction identifier.
+///
+/// ## Example
+///
+/// ```rust
+/// use visa_sdk::api::visa_direct::query::models::QueryFilterBuilder;
+///
+/// let filter = QueryFilterBuilder::default()
+///     .acquiring_bin(408999)
+///     .retrieval_reference_number(Some("412770451000".to_string()))
+///     .systems_trace_audit_number(Some(451000))
+///     .build()
+///     .expect("Failed to build QueryFilter");
+/// ```
+#[derive(Clone, Debug, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+pub struct QueryFilter {
+    /// The acquiring BIN the transactions were submitted with.
+    ///
+    /// Example: `408999`
+    pub acquiring_bin: u32,
+
+    /// The retrieval reference number of the transaction.
+    ///
+    /// Example: `"412770451000"`
+    #[builder(default)]
+    pub retrieval_reference_number: Option<String>,
+
+    /// The systems trace audit number of the transaction.
+    ///
+    /// Example: `451000`
+    #[builder(default)]
+    pub systems_trace_audit_number: Option<u32>,
+
+    /// Visa's identifier of the transaction.
+    ///
+    /// Example: `381228649430015`
+    #[builder(default)]
+    pub transaction_identifier: Option<u64>,
+
+    /// The first day to search, as `YYYY-MM-DD`.
+    ///
+    /// Example: `"2024-01-08"`
+    #[builder(default)]
+    pub from_date: Option<String>,
+
+    /// The last day to search, as `YYYY-MM-DD`.
+    ///
+    /// Example: `"2024-01-09"`
+    #[builder(default)]
+    pub to_date: Option<String>,
+
+    /// The number of results per page. Visa's default applies when unset.
+    ///
+    /// Example: `50`
+    #[builder(default)]
+    pub page_size: Option<u32>,
+}
+
+impl QueryFilterBuilder {
+    fn validate(&self) -> BuilderResult<()> {
+        let retrieval_reference_number = self.retrieval_reference_number.clone().flatten();
+        let systems_trace_audit_number = self.systems_trace_audit_number.flatten();
+        if retrieval_reference_number.is_none()
+            && systems_trace_audit_number.is_none()
+            && !matches!(self.transaction_identifier, Some(Some(_)))
+        {
+            return Err(BuilderError::ValidationViolition(
+                "one of retrieval_reference_number, systems_trace_audit_number or \
+                 transaction_identifier is required"
+                    .to_string(),
+            ));
+        }
+        if matches!(self.page_size, Some(Some(0))) {
+            return Err(BuilderError::ValidationViolition(
+                "page_size cannot be 0".to_string(),
+            ));
+        }
+        validate_identification(
+            systems_trace_audit_number,
+            retrieval_reference_number.as_deref(),
+            None,
+        )
+    }
+}
+
+impl QueryFilter {
+    /// The query parameters of the page starting at `start_index`.
+    pub(crate) fn query_pairs(&self, start_index: u32) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![("acquiringBIN", self.acquiring_bin.to_string())];
+        if let Some(rrn) = &self.retrieval_reference_number {
+            pairs.push(("rrn", rrn.clone()));
+        }
+        if let Some(stan) = self.systems_trace_audit_number {
+            pairs.push(("stan", stan.to_string()));
+        }
+        if let Some(transaction_identifier) = self.transaction_identifier {
+            pairs.push(("transactionIdentifier", transaction_identifier.to_string()));
+        }
+        if let Some(from_date) = &self.from_date {
+            pairs.push(("fromDate", from_date.clone()));
+        }
+        if let Some(to_date) = &self.to_date {
+            pairs.push(("toDate", to_date.clone()));
+        }
+        if let Some(page_size) = self.page_size {
+            pairs.push(("pageSize", page_size.to_string()));
+        }
+        if start_index > 0 {
+            pairs.push(("startIndex", start_index.to_string()));
+        }
+        pairs
+    }
+}
+
+/// A page of Transaction Query API results.
+///
+/// Example response:
+/// ```json
+/// {
+///     "transactions": [
+///         {
+///             "transactionIdentifier": 381228649430015,
+///             "acquiringBIN": 408999,
+///             "rrn": "412770451000",
+///             "stan": 451000,
+///             "transactionType": "OCT",
+///             "actionCode": "00",
+///             "approvalCode": "20304B",
+///             "amount": "124.05",
+///             "currencyCode": "USD"
+///         }
+///     ],
+///     "totalCount": 1
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryPage {
+    /// The transactions of the page.
+    #[serde(default)]
+    pub transactions: Vec<TransactionRecord>,
+
+    /// The number of transactions matching the filter, over all pages.
+    ///
+    /// Example: `1`
+    pub total_count: Option<u32>,
+}
+
+/// A Visa Direct transaction, as returned by the Transaction Query API.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionRecord {
+    /// Visa's identifier of the transaction.
+    ///
+    /// Example: `381228649430015`
+    pub transaction_identifier: Option<u64>,
+
+    /// The acquiring BIN of the transaction.
+    ///
+    /// Example: `408999`
+    #[serde(rename = "acquiringBIN")]
+    pub acquiring_bin: Option<u32>,
+
+    /// The retrieval reference number of the transaction.
+    ///
+    /// Example: `"412770451000"`
+    #[serde(rename = "rrn")]
+    pub retrieval_reference_number: Option<String>,
+
+    /// The systems trace audit number of the transaction.
+    ///
+    /// Example: `451000`
+    #[serde(rename = "stan")]
+    pub systems_trace_audit_number: Option<u32>,
+
+    /// The type of the transaction, e.g. `"AFT"` for a pull, or `"OCT"` for
+    /// a push.
+    ///
+    /// Example: `"OCT"`
+    pub transaction_type: Option<String>,
+
+    /// The result of the transaction, `"00"` when approved.
+    ///
+    /// Example: `"00"`
+    pub action_code: Option<String>,
+
+    /// The approval code of an approved transaction.
+    ///
+    /// Example: `"20304B"`
+    pub approval_code: Option<String>,
+
+    /// The date and time the transaction was transmitted to Visa.
+    ///
+    /// Example: `"2024-01-08T10:22:15.000Z"`
+    pub transmission_date_time: Option<String>,
+
+    /// The amount of the transaction.
+    ///
+    /// Example: `"124.05"`
+    pub amount: Option<Amount>,
+
+    /// The currency of the amount.
+    ///
+    /// Example: `"USD"`
+    pub currency_code: Option<String>,
+}
+
+impl TransactionRecord {
+    /// Returns `true` if the transaction was approved.
+    pub fn is_approved(&self) -> bool {
+        self.action_code.as_deref() == Some("00")
+    }
+}
+
+/// The outcome of a push whose response was lost, e.g. on a timeout.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PushReconciliation {
+    /// Visa approved the push: do not resubmit it.
+    Approved(TransactionRecord),
+
+    /// Visa is still processing the push: do not resubmit it, reconcile it
+    /// again later.
+    Pending(TransactionRecord),
+
+    /// Visa processed and declined the push. Resubmitting it is a new
+    /// transaction, with a new STAN and RRN.
+    Declined(TransactionRecord),
+
+    /// Visa has no record of the push.
+    NotFound,
+}
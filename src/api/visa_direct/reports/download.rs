@@ -0,0 +1,426 @@
+//! Streaming download of a report, see [`ReportDownload`].
+
+use super::models::ReportFormat;
+use crate::api::{
+    response::{api_error, correlation_id, CallMetadata},
+    result::{Error, Result},
+};
+use reqwest::{header, header::HeaderMap, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::time::Duration;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// A report being downloaded.
+///
+/// The body is read from the connection as it is consumed, so a large report
+/// is never held in memory: either write it to a file with
+/// [`copy_to`][Self::copy_to], or read its rows one at a time with
+/// [`next_record`][Self::next_record].
+///
+/// With Message Level Encryption, the client decrypts the whole body before
+/// returning it, so the report is held in memory once.
+#[derive(Debug)]
+pub struct ReportDownload {
+    response: Response,
+    format: ReportFormat,
+    status: StatusCode,
+    correlation_id: Option<String>,
+    headers: HeaderMap,
+    latency: Duration,
+    parser: RowParser,
+    eof: bool,
+}
+
+impl ReportDownload {
+    /// Wraps the response of a download. Non-2xx responses are turned into
+    /// a [`VisaApiError`][crate::api::result::VisaApiError].
+    pub(crate) async fn new(response: Response) -> Result<Self> {
+        if !response.status().is_success() {
+            return Err(api_error(response).await.into());
+        }
+
+        let headers = response.headers().clone();
+        let format = match headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+        {
+            Some(content_type) if content_type.contains("csv") => ReportFormat::Csv,
+            _ => ReportFormat::Json,
+        };
+        Ok(ReportDownload {
+            status: response.status(),
+            correlation_id: correlation_id(&response),
+            latency: response
+                .extensions()
+                .get::<CallMetadata>()
+                .map(|metadata| metadata.latency)
+                .unwrap_or_default(),
+            headers,
+            format,
+            parser: RowParser::new(format),
+            eof: false,
+            response,
+        })
+    }
+
+    /// The file format of the report, from the `Content-Type` of the
+    /// response: CSV for `text/csv`, JSON otherwise.
+    pub fn format(&self) -> ReportFormat {
+        self.format
+    }
+
+    /// The HTTP status of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The `x-correlation-id` of the call, as returned by Visa, or as sent by
+    /// the client.
+    pub fn correlation_id(&self) -> Option<&str> {
+        self.correlation_id.as_deref()
+    }
+
+    /// The response headers.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The time from sending the request until the response headers, retries
+    /// included.
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+
+    /// The size of the report, in bytes, if announced by Visa.
+    pub fn content_length(&self) -> Option<u64> {
+        self.response.content_length()
+    }
+
+    /// Writes the rest of the report to `writer` as it is received, and
+    /// returns the number of bytes written.
+    ///
+    /// # Arguments
+    ///
+    /// - `writer`: Where to write the report, e.g. a
+    ///   [`tokio::fs::File`](https://docs.rs/tokio/latest/tokio/fs/struct.File.html).
+    pub async fn copy_to<W: AsyncWrite + Unpin>(mut self, writer: &mut W) -> Result<u64> {
+        let buffered = self.take_buffered();
+        writer.write_all(&buffered).await.map_err(Error::custom)?;
+        let mut written = buffered.len() as u64;
+
+        while let Some(chunk) = self.next_chunk().await? {
+            let chunk = chunk.as_ref();
+            writer.write_all(chunk).await.map_err(Error::custom)?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await.map_err(Error::custom)?;
+        Ok(written)
+    }
+
+    /// The bytes received but not read as rows yet.
+    pub(crate) fn take_buffered(&mut self) -> Vec<u8> {
+        self.parser.take_remaining()
+    }
+
+    /// The next bytes of the report, as received, without parsing them.
+    pub(crate) async fn next_chunk(&mut self) -> Result<Option<impl AsRef<[u8]>>> {
+        Ok(self.response.chunk().await?)
+    }
+
+    /// Reads the next row of the report into `T`, e.g. a
+    /// [`SettlementRecord`][super::models::SettlementRecord]. Returns
+    /// `None` once every row was read.
+    ///
+    /// CSV rows are mapped to `T` by the names of the header row, with every
+    /// cell read as a string, and empty cells as missing.
+    pub async fn next_record<T: DeserializeOwned>(&mut self) -> Result<Option<T>> {
+        loop {
+            if let Some(row) = self.parser.next_row(self.eof)? {
+                return Ok(Some(serde_json::from_value(row)?));
+            }
+            if self.eof {
+                return Ok(None);
+            }
+            match self.response.chunk().await? {
+                Some(chunk) => self.parser.feed(&chunk),
+                None => self.eof = true,
+            }
+        }
+    }
+
+    /// Reads every remaining row of the report into `T`. Only the parsed rows
+    /// are held in memory, not the report itself.
+    pub async fn records<T: DeserializeOwned>(mut self) -> Result<Vec<T>> {
+        let mut records = Vec::new();
+        while let Some(record) = self.next_record().await? {
+            records.push(record);
+        }
+        Ok(records)
+    }
+}
+
+/// Where a JSON report is at.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum JsonState {
+    /// Nothing read yet.
+    Start,
+    /// In a top-level array of rows.
+    Array,
+    /// In a sequence of rows, e.g. one per line.
+    Sequence,
+    /// Past the end of the top-level array.
+    Done,
+}
+
+/// Splits the bytes of a report into rows, as they arrive.
+#[derive(Debug)]
+pub(crate) struct RowParser {
+    format: ReportFormat,
+    buffer: Vec<u8>,
+    position: usize,
+    header: Option<Vec<String>>,
+    json_state: JsonState,
+}
+
+impl RowParser {
+    pub(crate) fn new(format: ReportFormat) -> Self {
+        RowParser {
+            format,
+            buffer: Vec::new(),
+            position: 0,
+            header: None,
+            json_state: JsonState::Start,
+        }
+    }
+
+    /// Appends the next bytes of the report.
+    pub(crate) fn feed(&mut self, chunk: &[u8]) {
+        self.buffer.drain(..self.position);
+        self.position = 0;
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// The bytes fed but not parsed yet.
+    fn take_remaining(&mut self) -> Vec<u8> {
+        let remaining = self.buffer.split_off(self.position);
+        self.buffer.clear();
+        self.position = 0;
+        remaining
+    }
+
+    /// The next complete row, as a JSON value. Returns `None` when more bytes
+    /// are needed, or, once `eof` is set, when every row was read.
+    pub(crate) fn next_row(&mut self, eof: bool) -> Result<Option<Value>> {
+        match self.format {
+            ReportFormat::Csv => self.next_csv_row(eof),
+            ReportFormat::Json => self.next_json_row(eof),
+        }
+    }
+
+    fn next_csv_row(&mut self, eof: bool) -> Result<Option<Value>> {
+        loop {
+            let Some(line) = self.next_csv_line(eof)? else {
+                return Ok(None);
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            let cells = split_csv_line(&line)?;
+            let Some(header) = &self.header else {
+                let header = cells
+                    .into_iter()
+                    .map(|name| name.trim_start_matches('\u{feff}').trim().to_string())
+                    .collect();
+                self.header = Some(header);
+                continue;
+            };
+            if cells.len() != header.len() {
+                return Err(Error::custom(format!(
+                    "report row has {} cells, the header has {}",
+                    cells.len(),
+                    header.len()
+                )));
+            }
+
+            let row: Map<String, Value> = header
+                .iter()
+                .zip(cells)
+                .map(|(name, cell)| {
+                    let value = match cell.is_empty() {
+                        true => Value::Null,
+                        false => Value::String(cell),
+                    };
+                    (name.clone(), value)
+                })
+                .collect();
+            return Ok(Some(Value::Object(row)));
+        }
+    }
+
+    /// The next CSV record, up to a line feed outside of quotes.
+    fn next_csv_line(&mut self, eof: bool) -> Result<Option<String>> {
+        let remaining = &self.buffer[self.position..];
+        let mut in_quotes = false;
+        let end = remaining.iter().position(|byte| match byte {
+            b'"' => {
+                in_quotes = !in_quotes;
+                false
+            }
+            b'\n' => !in_quotes,
+            _ => false,
+        });
+        let (line, consumed) = match end {
+            Some(end) => (&remaining[..end], end + 1),
+            None if eof && !remaining.is_empty() => (remaining, remaining.len()),
+            None => return Ok(None),
+        };
+        let line = std::str::from_utf8(line)
+            .map_err(Error::custom)?
+            .trim_end_matches('\r')
+            .to_string();
+        self.position += consumed;
+        Ok(Some(line))
+    }
+
+    fn next_json_row(&mut self, eof: bool) -> Result<Option<Value>> {
+        loop {
+            self.skip_json_separators();
+            let Some(&next) = self.buffer.get(self.position) else {
+                if eof && self.json_state == JsonState::Array {
+                    return Err(Error::custom("report ended inside of its array"));
+                }
+                return Ok(None);
+            };
+            match (self.json_state, next) {
+                (JsonState::Start, b'[') => {
+                    self.position += 1;
+                    self.json_state = JsonState::Array;
+                }
+                (JsonState::Start, _) => self.json_state = JsonState::Sequence,
+                (JsonState::Array, b']') => {
+                    self.position += 1;
+                    self.json_state = JsonState::Done;
+                }
+                (JsonState::Done, _) => {
+                    return Err(Error::custom("unexpected data after the report array"))
+                }
+                (JsonState::Array | JsonState::Sequence, _) => break,
+            }
+        }
+
+        let mut rows =
+            serde_json::Deserializer::from_slice(&self.buffer[self.position..]).into_iter();
+        match rows.next() {
+            Some(Ok(row)) => {
+                self.position += rows.byte_offset();
+                Ok(Some(row))
+            }
+            Some(Err(error)) if error.is_eof() && !eof => Ok(None),
+            Some(Err(error)) => Err(error.into()),
+            None => Ok(None),
+        }
+    }
+
+    /// Skips the whitespace, and the commas between the rows of an array.
+    fn skip_json_separators(&mut self) {
+        while let Some(byte) = self.buffer.get(self.position) {
+            match byte {
+                b',' if self.json_state == JsonState::Array => self.position += 1,
+                byte if byte.is_ascii_whitespace() => self.position += 1,
+                _ => break,
+            }
+        }
+    }
+}
+
+/// Splits a CSV record into its cells, unquoting them.
+fn split_csv_line(line: &str) -> Result<Vec<String>> {
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = line.chars().peekable();
+    let mut in_quotes = false;
+    while let Some(char) = chars.next() {
+        match (char, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if cell.is_empty() => in_quotes = true,
+            (',', false) => cells.push(std::mem::take(&mut cell)),
+            (char, _) => cell.push(char),
+        }
+    }
+    if in_quotes {
+        return Err(Error::custom("unterminated quote in report row"));
+    }
+    cells.push(cell);
+    Ok(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Feeds `report` one byte at a time, as the worst case of chunking.
+    fn parse_bytewise(format: ReportFormat, report: &str) -> Result<Vec<Value>> {
+        let mut parser = RowParser::new(format);
+        let mut rows = Vec::new();
+        for byte in report.as_bytes() {
+            parser.feed(&[*byte]);
+            while let Some(row) = parser.next_row(false)? {
+                rows.push(row);
+            }
+        }
+        while let Some(row) = parser.next_row(true)? {
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+
+    #[test]
+    fn test_row_parser_csv() {
+        let report = "\u{feff}rrn,stan,note\r\n\
+                      412770451000,451000,\"Acme, \"\"Inc\"\"\"\r\n\
+                      \r\n\
+                      412770451001,,\"multi\nline\"";
+
+        let rows = parse_bytewise(ReportFormat::Csv, report).unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                json!({ "rrn": "412770451000", "stan": "451000", "note": "Acme, \"Inc\"" }),
+                json!({ "rrn": "412770451001", "stan": null, "note": "multi\nline" }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_row_parser_csv_rejects_ragged_rows() {
+        let result = parse_bytewise(ReportFormat::Csv, "rrn,stan\n412770451000\n");
+        assert!(matches!(result, Err(Error::Custom(_))));
+    }
+
+    #[test]
+    fn test_row_parser_json() {
+        let array = r#" [ {"stan": 451000, "note": "a, [b]"} , {"stan": "451001"} ] "#;
+        let lines = "{\"stan\": 451000, \"note\": \"a, [b]\"}\n{\"stan\": \"451001\"}\n";
+
+        for report in [array, lines] {
+            let rows = parse_bytewise(ReportFormat::Json, report).unwrap();
+            assert_eq!(
+                rows,
+                vec![
+                    json!({ "stan": 451000, "note": "a, [b]" }),
+                    json!({ "stan": "451001" }),
+                ]
+            );
+        }
+
+        let result = parse_bytewise(ReportFormat::Json, r#"[{"stan": 451000}"#);
+        assert!(matches!(result, Err(Error::Custom(_))));
+    }
+}
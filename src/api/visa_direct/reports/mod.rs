@@ -0,0 +1,541 @@
+//! # Reports API Module
+//!
+//! This module provides access to the Visa Direct Reports API, which
+//! generates and serves transaction and settlement reports.
+//!
+//! ## Overview
+//!
+//! The [`Reports`] struct is the main entry point:
+//!
+//! - [`Reports::list`] lists the reports, e.g. the daily settlement reports
+//!   of a week.
+//! - [`Reports::request`] asks Visa to generate a report.
+//! - [`Reports::get`] fetches the status of a report, until it is
+//!   [available][models::ReportMetadata::is_available].
+//! - [`Reports::download`] downloads a report, as a [`ReportDownload`].
+//!
+//! Reports can be large, so a [`ReportDownload`] is streamed rather than
+//! buffered: it is written to a file as it is received, or parsed one row at a
+//! time into typed records like [`models::SettlementRecord`].
+//! The request timeout of the client covers the whole download: raise it with
+//! [`Reports::with_request_options`] for large reports.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! # use visa_sdk::client::{state::WithoutMessageLevelEncryption, VisaClient};
+//! use visa_sdk::api::visa_direct::reports::{models::*, Reports};
+//!
+//! # async fn example(
+//! #     client: VisaClient<WithoutMessageLevelEncryption>,
+//! # ) -> Result<(), Box<dyn std::error::Error>> {
+//! let reports = Reports::new(client);
+//! let filter = ReportFilterBuilder::default()
+//!     .report_type(Some(ReportType::SettlementDetail))
+//!     .from_date(Some("2024-01-08".to_string()))
+//!     .to_date(Some("2024-01-08".to_string()))
+//!     .build()?;
+//!
+//! for report in reports.list(&filter).await?.body.reports {
+//!     if !report.is_available() {
+//!         continue;
+//!     }
+//!     let mut download = reports.download(&report.report_id).await?;
+//!     while let Some(record) = download.next_record::<SettlementRecord>().await? {
+//!         println!("{:?} {:?}", record.transaction_identifier, record.settlement_amount);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Documentation
+//!
+//! For more details, consult the Visa API documentation:
+//! - [Visa Direct API
+//!   Reference](https://developer.visa.com/capabilities/visa_direct/reference)
+
+mod download;
+pub mod models;
+
+#[double]
+use crate::client::VisaClient;
+use crate::{
+    api::{
+        request::json_request,
+        response::{parse_response, VisaResponse},
+        result::Result,
+    },
+    client::{models::RequestOptions, utils::MLETrait},
+};
+pub use download::ReportDownload;
+use mockall_double::double;
+use models::*;
+use reqwest::{header, Method, Request};
+use url::Url;
+
+/// [`Reports`] struct provides methods to interact with the Visa Direct
+/// Reports API.
+///
+/// # Example
+///
+/// ```no_run
+/// # use visa_sdk::client::{state::WithoutMessageLevelEncryption, VisaClient};
+/// use visa_sdk::api::visa_direct::reports::{models::*, Reports};
+///
+/// # async fn example(client: VisaClient<WithoutMessageLevelEncryption>) {
+/// let reports = Reports::new(client);
+/// let request = ReportRequestBuilder::default()
+///     .report_type(ReportType::SettlementDetail)
+///     .format(ReportFormat::Csv)
+///     .from_date("2024-01-08")
+///     .to_date("2024-01-08")
+///     .build()
+///     .expect("Failed to build ReportRequest");
+/// let report = reports.request(request).await.expect("Failed to request report");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Reports<MLE>
+where
+    MLE: MLETrait,
+{
+    client: VisaClient<MLE>,
+    url: Url,
+    options: RequestOptions,
+}
+
+impl<MLE> Reports<MLE>
+where
+    MLE: MLETrait,
+{
+    const URL: &'static str = "visadirect/reports/v1/reports";
+    const CONTENT: &'static str = "content";
+
+    /// Creates a new instance of `Reports`.
+    ///
+    /// # Arguments
+    ///
+    /// - `client`: A `VisaClient` instance. Does not need to have Message Level
+    ///   Encryption enabled.
+    pub fn new(client: VisaClient<MLE>) -> Self {
+        let url = client.get_base_url().join(Self::URL).unwrap();
+        Reports {
+            client,
+            url,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Sets the [`RequestOptions`] of every call. A correlation ID set here is
+    /// shared by all the calls.
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Lists the reports matching `filter`.
+    ///
+    /// # Arguments
+    ///
+    /// - `filter`: An instance of `ReportFilter`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "reports.list",
+            skip_all,
+            fields(
+                method = "GET",
+                path = "/visadirect/reports/v1/reports",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn list(&self, filter: &ReportFilter) -> Result<VisaResponse<ReportList>> {
+        let mut url = self.url.clone();
+        let pairs = filter.query_pairs();
+        if !pairs.is_empty() {
+            url.query_pairs_mut().extend_pairs(pairs);
+        }
+
+        let response = self
+            .client
+            .execute_request_with_options(get_request(url, "application/json"), &self.options)
+            .await?;
+        parse_response::<ReportList>(response).await
+    }
+
+    /// Asks Visa to generate a report. The report is usually not available
+    /// right away: poll it with [`Reports::get`].
+    ///
+    /// # Arguments
+    ///
+    /// - `payload`: An instance of `ReportRequest`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "reports.request",
+            skip_all,
+            fields(
+                method = "POST",
+                path = "/visadirect/reports/v1/reports",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn request(&self, payload: ReportRequest) -> Result<VisaResponse<ReportMetadata>> {
+        let request = json_request(Method::POST, self.url.clone(), &payload)?;
+        let response = self
+            .client
+            .execute_request_with_options(request, &self.options)
+            .await?;
+        parse_response::<ReportMetadata>(response).await
+    }
+
+    /// Fetches a report, e.g. to check whether it is available.
+    ///
+    /// # Arguments
+    ///
+    /// - `report_id`: The identifier of the report.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "reports.get",
+            skip_all,
+            fields(
+                method = "GET",
+                path = "/visadirect/reports/v1/reports/{id}",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn get(&self, report_id: &str) -> Result<VisaResponse<ReportMetadata>> {
+        let url = self.report_url(report_id, None);
+        let response = self
+            .client
+            .execute_request_with_options(get_request(url, "application/json"), &self.options)
+            .await?;
+        parse_response::<ReportMetadata>(response).await
+    }
+
+    /// Downloads an available report. The body is not read yet: consume it
+    /// with the methods of [`ReportDownload`].
+    ///
+    /// # Arguments
+    ///
+    /// - `report_id`: The identifier of the report.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "reports.download",
+            skip_all,
+            fields(
+                method = "GET",
+                path = "/visadirect/reports/v1/reports/{id}/content",
+                api_level = ?self.client.get_config().api_level,
+            )
+        )
+    )]
+    pub async fn download(&self, report_id: &str) -> Result<ReportDownload> {
+        let url = self.report_url(report_id, Some(Self::CONTENT));
+        let request = get_request(url, "text/csv, application/json");
+        let response = self
+            .client
+            .execute_request_with_options(request, &self.options)
+            .await?;
+        ReportDownload::new(response).await
+    }
+
+    fn report_url(&self, report_id: &str, resource: Option<&str>) -> Url {
+        let mut url = self.url.clone();
+        url.path_segments_mut()
+            .unwrap()
+            .push(report_id)
+            .extend(resource);
+        url
+    }
+}
+
+fn get_request(url: Url, accept: &'static str) -> Request {
+    let mut request = Request::new(Method::GET, url);
+    request
+        .headers_mut()
+        .insert(header::ACCEPT, header::HeaderValue::from_static(accept));
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::result::Error;
+    use crate::client::models::{ApiLevel, Config};
+    use crate::money::Amount;
+    use http::response::Builder as ResponseBuilder;
+    use serde_json::json;
+
+    const MOCK_URL: &str = "https://domain.test";
+
+    fn setup_mock_client(
+        mock_client: &mut VisaClient<()>,
+        method: Method,
+        url: &str,
+        content_type: &str,
+        status: u16,
+        body: &str,
+    ) {
+        let response = ResponseBuilder::new()
+            .status(status)
+            .header("content-type", content_type)
+            .body(body.to_string())
+            .unwrap();
+
+        let method_clone = method.clone();
+        let url_clone = url.to_string();
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(move |request, _| {
+                request.method() == method_clone && request.url().as_str() == url_clone
+            })
+            .times(1)
+            .returning(move |_, _| Ok(response.clone().into()));
+        mock_client.expect_get_config().return_const(Config {
+            api_level: ApiLevel::Sandbox,
+            ..Default::default()
+        });
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse(MOCK_URL).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_reports_list() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_client(
+            &mut mock_client,
+            Method::GET,
+            "https://domain.test/visadirect/reports/v1/reports?reportType=SETTLEMENT_DETAIL&fromDate=2024-01-08",
+            "application/json",
+            200,
+            r#"{
+                "reports": [
+                    {
+                        "reportId": "RPT-20240108-0001",
+                        "reportType": "SETTLEMENT_DETAIL",
+                        "format": "CSV",
+                        "status": "AVAILABLE",
+                        "size": 1048576
+                    },
+                    {
+                        "reportId": "RPT-20240108-0002",
+                        "reportType": "CHARGEBACK_DETAIL",
+                        "status": "IN_PROGRESS"
+                    }
+                ]
+            }"#,
+        );
+
+        let reports = Reports::new(mock_client);
+        let filter = ReportFilterBuilder::default()
+            .report_type(Some(ReportType::SettlementDetail))
+            .from_date(Some("2024-01-08".to_string()))
+            .build()
+            .unwrap();
+        let result = reports.list(&filter).await.unwrap();
+
+        assert!(result.reports[0].is_available());
+        assert_eq!(result.reports[0].size, Some(1048576));
+        assert_eq!(result.reports[1].report_type, Some(ReportType::Other));
+        assert!(!result.reports[1].is_available());
+    }
+
+    #[tokio::test]
+    async fn test_reports_request() {
+        let mut mock_client = VisaClient::<()>::new();
+        let response = ResponseBuilder::new()
+            .status(200)
+            .body(r#"{"reportId": "RPT-20240108-0001", "status": "PENDING"}"#.to_string())
+            .unwrap();
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|request, _| {
+                let body: serde_json::Value =
+                    serde_json::from_slice(request.body().unwrap().as_bytes().unwrap()).unwrap();
+                request.method() == Method::POST
+                    && request.url().as_str() == "https://domain.test/visadirect/reports/v1/reports"
+                    && body
+                        == json!({
+                            "reportType": "SETTLEMENT_DETAIL",
+                            "format": "JSON",
+                            "fromDate": "2024-01-01",
+                            "toDate": "2024-01-08"
+                        })
+            })
+            .returning(move |_, _| Ok(response.clone().into()));
+        mock_client
+            .expect_get_config()
+            .return_const(Config::default());
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse(MOCK_URL).unwrap());
+
+        let reports = Reports::new(mock_client);
+        let payload = ReportRequestBuilder::default()
+            .report_type(ReportType::SettlementDetail)
+            .format(ReportFormat::Json)
+            .from_date("2024-01-01")
+            .to_date("2024-01-08")
+            .build()
+            .unwrap();
+        let result = reports.request(payload).await.unwrap();
+
+        assert_eq!(result.report_id, "RPT-20240108-0001");
+        assert_eq!(result.body.status, Some(ReportStatus::Pending));
+    }
+
+    #[tokio::test]
+    async fn test_reports_download_csv() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_client(
+            &mut mock_client,
+            Method::GET,
+            "https://domain.test/visadirect/reports/v1/reports/RPT-20240108-0001/content",
+            "text/csv; charset=utf-8",
+            200,
+            "transactionIdentifier,transactionType,acquiringBIN,rrn,stan,settlementDate,amount,currencyCode,settlementAmount,interchangeFeeAmount\n\
+             381228649430015,OCT,408999,412770451000,451000,2024-01-08,124.05,USD,124.05,\n\
+             381228649430016,AFT,408999,412770451001,451001,2024-01-08,10.00,USD,10.00,0.25\n",
+        );
+
+        let reports = Reports::new(mock_client);
+        let download = reports.download("RPT-20240108-0001").await.unwrap();
+        assert_eq!(download.format(), ReportFormat::Csv);
+
+        let records = download.records::<SettlementRecord>().await.unwrap();
+        assert_eq!(
+            records[0],
+            SettlementRecord {
+                transaction_identifier: Some(381228649430015),
+                transaction_type: Some("OCT".to_string()),
+                acquiring_bin: Some(408999),
+                retrieval_reference_number: Some("412770451000".to_string()),
+                systems_trace_audit_number: Some(451000),
+                settlement_date: Some("2024-01-08".to_string()),
+                amount: Some(Amount::new(12405, 2)),
+                currency_code: Some("USD".to_string()),
+                settlement_amount: Some(Amount::new(12405, 2)),
+                ..Default::default()
+            }
+        );
+        assert_eq!(records[1].interchange_fee_amount, Some(Amount::new(25, 2)));
+    }
+
+    #[tokio::test]
+    async fn test_reports_download_json() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_client(
+            &mut mock_client,
+            Method::GET,
+            "https://domain.test/visadirect/reports/v1/reports/RPT-20240108-0001/content",
+            "application/json",
+            200,
+            r#"[
+                {"transactionIdentifier": 381228649430015, "stan": 451000, "amount": "124.05"},
+                {"transactionIdentifier": "381228649430016", "stan": "451001"}
+            ]"#,
+        );
+
+        let reports = Reports::new(mock_client);
+        let mut download = reports.download("RPT-20240108-0001").await.unwrap();
+
+        let first = download.next_record::<SettlementRecord>().await.unwrap();
+        assert_eq!(first.unwrap().transaction_identifier, Some(381228649430015));
+        let second = download.next_record::<SettlementRecord>().await.unwrap();
+        assert_eq!(second.unwrap().systems_trace_audit_number, Some(451001));
+        assert!(download
+            .next_record::<SettlementRecord>()
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reports_download_copy_to() {
+        let mut mock_client = VisaClient::<()>::new();
+        let body = "rrn,stan\n412770451000,451000\n";
+        setup_mock_client(
+            &mut mock_client,
+            Method::GET,
+            "https://domain.test/visadirect/reports/v1/reports/RPT%2F1/content",
+            "text/csv",
+            200,
+            body,
+        );
+
+        let reports = Reports::new(mock_client);
+        let download = reports.download("RPT/1").await.unwrap();
+        let mut file = Vec::new();
+        let written = download.copy_to(&mut file).await.unwrap();
+
+        assert_eq!(written, body.len() as u64);
+        assert_eq!(file, body.as_bytes());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_reports_download_span() {
+        let subscriber = crate::utils::trace::tests::CapturingSubscriber::default();
+        let _guard = tracing::subscriber::set_default(subscriber.clone());
+
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_client(
+            &mut mock_client,
+            Method::GET,
+            "https://domain.test/visadirect/reports/v1/reports/RPT-20240108-0001/content",
+            "text/csv",
+            200,
+            "rrn,stan\n",
+        );
+
+        let reports = Reports::new(mock_client);
+        reports.download("RPT-20240108-0001").await.unwrap();
+
+        let fields = subscriber.fields.lock().unwrap().join("\n");
+        assert!(fields.contains("span=reports.download"));
+        assert!(fields.contains("path=/visadirect/reports/v1/reports/{id}/content"));
+        assert!(fields.contains("api_level=Sandbox"));
+    }
+
+    #[tokio::test]
+    async fn test_reports_download_not_found() {
+        let mut mock_client = VisaClient::<()>::new();
+        setup_mock_client(
+            &mut mock_client,
+            Method::GET,
+            "https://domain.test/visadirect/reports/v1/reports/RPT-20240108-0001/content",
+            "application/json",
+            404,
+            r#"{"errorInfo": {"reason": "REPORT_NOT_FOUND", "message": "Report not found"}}"#,
+        );
+
+        let reports = Reports::new(mock_client);
+        let result = reports.download("RPT-20240108-0001").await;
+
+        match result {
+            Err(Error::VisaApiError(error)) => {
+                assert_eq!(error.code(), Some("REPORT_NOT_FOUND"))
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_report_request_rejects_reversed_dates() {
+        let result = ReportRequestBuilder::default()
+            .report_type(ReportType::SettlementDetail)
+            .format(ReportFormat::Csv)
+            .from_date("2024-01-08")
+            .to_date("2024-01-01")
+            .build();
+        assert!(result.is_err());
+    }
+}
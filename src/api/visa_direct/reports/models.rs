@@ -0,0 +1,390 @@
+//! # Reports Models
+//!
+//! This module contains the models of the Visa Direct Reports API.
+//!
+//! - [`ReportFilter`]: The reports to list, by type and date.
+//! - [`ReportRequest`]: A report to generate.
+//! - [`ReportMetadata`]: A report, as listed or requested, with its
+//!   [`ReportStatus`].
+//! - [`SettlementRecord`]: A row of a settlement report, see
+//!   [`ReportDownload::next_record`][super::ReportDownload::next_record].
+
+use crate::{
+    money::Amount,
+    utils::{BuilderError, BuilderResult},
+};
+use derive_builder::Builder;
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::{fmt::Display, str::FromStr};
+
+/// The content of a report.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReportType {
+    /// The settled transactions of a day, with their settlement amounts and
+    /// fees. Rows are [`SettlementRecord`]s.
+    SettlementDetail,
+    /// Every transaction of a day, settled or not.
+    TransactionDetail,
+    /// A report type unknown to this SDK. Only ever deserialized.
+    #[serde(other)]
+    Other,
+}
+
+/// The file format of a report.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ReportFormat {
+    /// Comma-separated values, with a header row of the column names.
+    Csv,
+    /// A JSON array of rows, or one JSON row per line.
+    Json,
+}
+
+/// The generation status of a report.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReportStatus {
+    /// The report is queued for generation.
+    Pending,
+    /// The report is being generated.
+    InProgress,
+    /// The report can be downloaded.
+    Available,
+    /// The report could not be generated.
+    Failed,
+    /// The report expired, and must be requested again.
+    Expired,
+    /// A status unknown to this SDK.
+    #[serde(other)]
+    Other,
+}
+
+/// Reports API filter, to list reports.
+///
+/// ## Example
+///
+/// ```rust
+/// use visa_sdk::api::visa_direct::reports::models::{ReportFilterBuilder, ReportType};
+///
+/// let filter = ReportFilterBuilder::default()
+///     .report_type(Some(ReportType::SettlementDetail))
+///     .from_date(Some("2024-01-01".to_string()))
+///     .to_date(Some("2024-01-08".to_string()))
+///     .build()
+///     .expect("Failed to build ReportFilter");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+pub struct ReportFilter {
+    /// The type of the reports.
+    ///
+    /// Example: `ReportType::SettlementDetail`
+    #[builder(default)]
+    pub report_type: Option<ReportType>,
+
+    /// The first day covered by the reports, as `YYYY-MM-DD`.
+    ///
+    /// Example: `"2024-01-01"`
+    #[builder(default)]
+    pub from_date: Option<String>,
+
+    /// The last day covered by the reports, as `YYYY-MM-DD`.
+    ///
+    /// Example: `"2024-01-08"`
+    #[builder(default)]
+    pub to_date: Option<String>,
+}
+
+impl ReportFilterBuilder {
+    fn validate(&self) -> BuilderResult<()> {
+        validate_dates(
+            self.from_date.clone().flatten().as_deref(),
+            self.to_date.clone().flatten().as_deref(),
+        )
+    }
+}
+
+impl ReportFilter {
+    /// The query parameters of the filter.
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+        if let Some(report_type) = self.report_type {
+            pairs.push(("reportType", enum_value(report_type)));
+        }
+        if let Some(from_date) = &self.from_date {
+            pairs.push(("fromDate", from_date.clone()));
+        }
+        if let Some(to_date) = &self.to_date {
+            pairs.push(("toDate", to_date.clone()));
+        }
+        pairs
+    }
+}
+
+/// The wire value of a unit enum variant.
+fn enum_value<T: Serialize>(value: T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(value)) => value,
+        _ => unreachable!("unit variants serialize as strings"),
+    }
+}
+
+/// Checks that a date range is not reversed. Dates are `YYYY-MM-DD`, so they
+/// compare as strings.
+fn validate_dates(from_date: Option<&str>, to_date: Option<&str>) -> BuilderResult<()> {
+    match (from_date, to_date) {
+        (Some(from_date), Some(to_date)) if from_date > to_date => Err(
+            BuilderError::ValidationViolition("from_date is after to_date".to_string()),
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Reports API request structure, to generate a report.
+///
+/// ## Example
+///
+/// ```rust
+/// use visa_sdk::api::visa_direct::reports::models::{
+///     ReportFormat, ReportRequestBuilder, ReportType,
+/// };
+///
+/// let request = ReportRequestBuilder::default()
+///     .report_type(ReportType::SettlementDetail)
+///     .format(ReportFormat::Csv)
+///     .from_date("2024-01-08")
+///     .to_date("2024-01-08")
+///     .build()
+///     .expect("Failed to build ReportRequest");
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+#[serde(rename_all = "camelCase")]
+pub struct ReportRequest {
+    /// The content of the report.
+    ///
+    /// Example: `ReportType::SettlementDetail`
+    pub report_type: ReportType,
+
+    /// The file format of the report.
+    ///
+    /// Example: `ReportFormat::Csv`
+    pub format: ReportFormat,
+
+    /// The first day covered by the report, as `YYYY-MM-DD`.
+    ///
+    /// Example: `"2024-01-08"`
+    #[builder(setter(into))]
+    pub from_date: String,
+
+    /// The last day covered by the report, as `YYYY-MM-DD`.
+    ///
+    /// Example: `"2024-01-08"`
+    #[builder(setter(into))]
+    pub to_date: String,
+
+    /// Restricts the report to the transactions of an acquiring BIN.
+    ///
+    /// Example: `408999`
+    #[builder(default)]
+    #[serde(rename = "acquiringBIN", skip_serializing_if = "Option::is_none")]
+    pub acquiring_bin: Option<u32>,
+}
+
+impl ReportRequestBuilder {
+    fn validate(&self) -> BuilderResult<()> {
+        if matches!(self.report_type, Some(ReportType::Other)) {
+            return Err(BuilderError::ValidationViolition(
+                "report_type cannot be Other".to_string(),
+            ));
+        }
+        validate_dates(self.from_date.as_deref(), self.to_date.as_deref())
+    }
+}
+
+/// A list of reports.
+///
+/// Example response:
+/// ```json
+/// {
+///     "reports": [
+///         {
+///             "reportId": "RPT-20240108-0001",
+///             "reportType": "SETTLEMENT_DETAIL",
+///             "format": "CSV",
+///             "status": "AVAILABLE",
+///             "fromDate": "2024-01-08",
+///             "toDate": "2024-01-08",
+///             "createdDateTime": "2024-01-09T02:00:00.000Z",
+///             "size": 1048576
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportList {
+    /// The reports matching the filter.
+    #[serde(default)]
+    pub reports: Vec<ReportMetadata>,
+}
+
+/// A report, as listed or requested.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportMetadata {
+    /// The identifier of the report, to download it.
+    ///
+    /// Example: `"RPT-20240108-0001"`
+    pub report_id: String,
+
+    /// The content of the report.
+    ///
+    /// Example: `ReportType::SettlementDetail`
+    pub report_type: Option<ReportType>,
+
+    /// The file format of the report.
+    ///
+    /// Example: `ReportFormat::Csv`
+    pub format: Option<ReportFormat>,
+
+    /// The generation status of the report.
+    ///
+    /// Example: `ReportStatus::Available`
+    pub status: Option<ReportStatus>,
+
+    /// The first day covered by the report.
+    ///
+    /// Example: `"2024-01-08"`
+    pub from_date: Option<String>,
+
+    /// The last day covered by the report.
+    ///
+    /// Example: `"2024-01-08"`
+    pub to_date: Option<String>,
+
+    /// The date and time the report was generated.
+    ///
+    /// Example: `"2024-01-09T02:00:00.000Z"`
+    pub created_date_time: Option<String>,
+
+    /// The size of the report file, in bytes.
+    ///
+    /// Example: `1048576`
+    pub size: Option<u64>,
+}
+
+impl ReportMetadata {
+    /// Returns `true` if the report can be downloaded.
+    pub fn is_available(&self) -> bool {
+        self.status == Some(ReportStatus::Available)
+    }
+}
+
+/// A row of a [`ReportType::SettlementDetail`] report.
+///
+/// CSV columns are named after the JSON keys, e.g. `transactionIdentifier`.
+/// Numbers are accepted both as JSON numbers and as strings, and empty CSV
+/// cells are read as missing.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementRecord {
+    /// Visa's identifier of the transaction.
+    ///
+    /// Example: `381228649430015`
+    #[serde(default, deserialize_with = "number_from_str")]
+    pub transaction_identifier: Option<u64>,
+
+    /// The type of the transaction, e.g. `"AFT"` for a pull, or `"OCT"` for
+    /// a push.
+    ///
+    /// Example: `"OCT"`
+    pub transaction_type: Option<String>,
+
+    /// The business application identifier of the transaction.
+    ///
+    /// Example: `"PP"`
+    pub business_application_id: Option<String>,
+
+    /// The acquiring BIN of the transaction.
+    ///
+    /// Example: `408999`
+    #[serde(rename = "acquiringBIN", default, deserialize_with = "number_from_str")]
+    pub acquiring_bin: Option<u32>,
+
+    /// The retrieval reference number of the transaction.
+    ///
+    /// Example: `"412770451000"`
+    #[serde(rename = "rrn")]
+    pub retrieval_reference_number: Option<String>,
+
+    /// The systems trace audit number of the transaction.
+    ///
+    /// Example: `451000`
+    #[serde(rename = "stan", default, deserialize_with = "number_from_str")]
+    pub systems_trace_audit_number: Option<u32>,
+
+    /// The date and time of the transaction.
+    ///
+    /// Example: `"2024-01-08T10:22:15.000Z"`
+    pub transaction_date_time: Option<String>,
+
+    /// The settlement date of the transaction, as `YYYY-MM-DD`.
+    ///
+    /// Example: `"2024-01-08"`
+    pub settlement_date: Option<String>,
+
+    /// The result of the transaction, `"00"` when approved.
+    ///
+    /// Example: `"00"`
+    pub action_code: Option<String>,
+
+    /// The amount of the transaction.
+    ///
+    /// Example: `"124.05"`
+    pub amount: Option<Amount>,
+
+    /// The currency of the amount.
+    ///
+    /// Example: `"USD"`
+    pub currency_code: Option<String>,
+
+    /// The amount settled.
+    ///
+    /// Example: `"124.05"`
+    pub settlement_amount: Option<Amount>,
+
+    /// The currency of the settled amount.
+    ///
+    /// Example: `"USD"`
+    pub settlement_currency_code: Option<String>,
+
+    /// The interchange fee of the transaction, in the settlement currency.
+    ///
+    /// Example: `"0.25"`
+    pub interchange_fee_amount: Option<Amount>,
+}
+
+/// Deserializes an optional number, from a JSON number or from a string.
+fn number_from_str<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString<T> {
+        Number(T),
+        String(String),
+    }
+
+    match Option::<NumberOrString<T>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(NumberOrString::Number(number)) => Ok(Some(number)),
+        Some(NumberOrString::String(string)) => {
+            string.trim().parse().map(Some).map_err(de::Error::custom)
+        }
+    }
+}
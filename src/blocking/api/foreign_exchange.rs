@@ -0,0 +1,151 @@
+//! Blocking version of [`api::foreign_exchange`][crate::api::foreign_exchange].
+
+use super::super::VisaClient;
+use crate::{
+    api::{
+        foreign_exchange::{self, enhanced::models::*, models::*},
+        response::VisaResponse,
+        result::Result,
+    },
+    client::{models::RequestOptions, state::WithMessageLevelEncryption, utils::MLETrait},
+};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`foreign_exchange::ForeignExchange`].
+pub struct ForeignExchange<MLE>
+where
+    MLE: MLETrait,
+{
+    inner: foreign_exchange::ForeignExchange<MLE>,
+    runtime: Arc<Runtime>,
+}
+
+impl<MLE> ForeignExchange<MLE>
+where
+    MLE: MLETrait,
+{
+    pub fn new(client: VisaClient<MLE>) -> Self {
+        ForeignExchange {
+            inner: foreign_exchange::ForeignExchange::new(client.inner),
+            runtime: client.runtime,
+        }
+    }
+
+    /// See [`foreign_exchange::ForeignExchange::with_request_options`].
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.inner = self.inner.with_request_options(options);
+        self
+    }
+
+    /// See [`foreign_exchange::ForeignExchange::get_a_or_b`].
+    pub fn get_a_or_b(&self, payload: FXRequest) -> Result<VisaResponse<FXResponseAorB>> {
+        self.runtime.block_on(self.inner.get_a_or_b(payload))
+    }
+
+    /// See [`foreign_exchange::ForeignExchange::get_bank_or_wallet`].
+    pub fn get_bank_or_wallet(
+        &self,
+        payload: FXRequest,
+    ) -> Result<VisaResponse<FXResponseBankOrWallet>> {
+        self.runtime
+            .block_on(self.inner.get_bank_or_wallet(payload))
+    }
+}
+
+/// Blocking version of [`foreign_exchange::EnhancedForeignExchange`].
+pub struct EnhancedForeignExchange {
+    inner: foreign_exchange::EnhancedForeignExchange,
+    runtime: Arc<Runtime>,
+}
+
+impl EnhancedForeignExchange {
+    pub fn new(client: VisaClient<WithMessageLevelEncryption>) -> Self {
+        EnhancedForeignExchange {
+            inner: foreign_exchange::EnhancedForeignExchange::new(client.inner),
+            runtime: client.runtime,
+        }
+    }
+
+    /// See [`foreign_exchange::EnhancedForeignExchange::with_request_options`].
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.inner = self.inner.with_request_options(options);
+        self
+    }
+
+    /// See [`foreign_exchange::EnhancedForeignExchange::get`].
+    pub fn get(&self, payload: EnhancedFXRequest) -> Result<VisaResponse<EnhancedFXResponse>> {
+        self.runtime.block_on(self.inner.get(payload))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::result::Error,
+        client::{models::Config, MockVisaClient},
+        money::{Amount, Rate},
+    };
+    use http::response::Builder as ResponseBuilder;
+    use url::Url;
+
+    fn forex(status: u16, body: &'static str) -> ForeignExchange<()> {
+        let mut mock_client = MockVisaClient::<()>::new();
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse("https://domain.test").unwrap());
+        mock_client
+            .expect_get_config()
+            .return_const(Config::default());
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|request, _| {
+                request.url().as_str() == "https://domain.test/forexrates/v2/foreignexchangerates"
+            })
+            .returning(move |_, _| {
+                Ok(ResponseBuilder::new()
+                    .status(status)
+                    .body(body)
+                    .unwrap()
+                    .into())
+            });
+
+        ForeignExchange::new(VisaClient::new(mock_client).unwrap())
+    }
+
+    fn payload() -> FXRequest {
+        FXRequestBuilder::default()
+            .rate_product_code(RateProduct::A)
+            .source_currency_code("USD")
+            .destination_currency_code("GBP")
+            .source_amount(Some(Amount::new(10055, 2)))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_foreign_exchange_get_a_or_b() {
+        let forex = forex(
+            200,
+            r#"{"conversion_rate": "0.07", "destination_amount": "75.85"}"#,
+        );
+
+        let response = forex.get_a_or_b(payload()).unwrap();
+        assert_eq!(response.conversion_rate, Rate::new(7, 2));
+        assert_eq!(response.destination_amount, Amount::new(7585, 2));
+    }
+
+    #[test]
+    fn test_foreign_exchange_error() {
+        let forex = forex(
+            400,
+            r#"{"responseStatus": {"status": 400, "code": "9125", "severity": "ERROR", "message": "Expected input credential was not present", "info": ""}}"#,
+        );
+
+        assert!(matches!(
+            forex.get_a_or_b(payload()),
+            Err(Error::VisaApiError(error)) if error.code() == Some("9125")
+        ));
+    }
+}
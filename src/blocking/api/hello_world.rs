@@ -0,0 +1,85 @@
+//! Blocking version of [`api::hello_world`][crate::api::hello_world].
+
+use super::super::VisaClient;
+use crate::{
+    api::{
+        hello_world::{self, models::*},
+        response::VisaResponse,
+        result::Result,
+    },
+    client::{models::RequestOptions, utils::MLETrait},
+};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`hello_world::HelloWorld`].
+pub struct HelloWorld<MLE>
+where
+    MLE: MLETrait,
+{
+    inner: hello_world::HelloWorld<MLE>,
+    runtime: Arc<Runtime>,
+}
+
+impl<MLE> HelloWorld<MLE>
+where
+    MLE: MLETrait,
+{
+    pub fn new(client: VisaClient<MLE>) -> Self {
+        HelloWorld {
+            inner: hello_world::HelloWorld::new(client.inner),
+            runtime: client.runtime,
+        }
+    }
+
+    /// See [`hello_world::HelloWorld::with_request_options`].
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.inner = self.inner.with_request_options(options);
+        self
+    }
+
+    /// See [`hello_world::HelloWorld::get`].
+    pub fn get(&self) -> Result<VisaResponse<HelloWorldResponse>> {
+        self.runtime.block_on(self.inner.get())
+    }
+
+    /// See [`hello_world::HelloWorld::ping`].
+    pub fn ping(&self) -> Result<Ping> {
+        self.runtime.block_on(self.inner.ping())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{models::Config, MockVisaClient};
+    use http::response::Builder as ResponseBuilder;
+    use url::Url;
+
+    #[test]
+    fn test_hello_world_get() {
+        let mut mock_client = MockVisaClient::<()>::new();
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse("https://domain.test").unwrap());
+        mock_client
+            .expect_get_config()
+            .return_const(Config::default());
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|request, _| request.url().as_str() == "https://domain.test/vdp/helloworld")
+            .returning(|_, _| {
+                Ok(ResponseBuilder::new()
+                    .status(200)
+                    .body(r#"{"timestamp": "2024-01-08T10:22:15", "message": "helloworld"}"#)
+                    .unwrap()
+                    .into())
+            });
+
+        let hello_world = HelloWorld::new(VisaClient::new(mock_client).unwrap());
+        let response = hello_world.get().unwrap();
+
+        assert_eq!(response.message, "helloworld");
+        assert_eq!(hello_world.ping().unwrap().response.message, "helloworld");
+    }
+}
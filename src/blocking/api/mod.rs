@@ -0,0 +1,8 @@
+//! Blocking versions of the [API modules][crate::api]. They share the models
+//! and errors of the async modules.
+
+pub mod foreign_exchange;
+
+pub mod hello_world;
+
+pub mod visa_direct;
@@ -0,0 +1,123 @@
+//! Blocking version of
+//! [`api::visa_direct::funds_transfer`][crate::api::visa_direct::funds_transfer].
+
+use crate::{
+    api::{
+        response::VisaResponse,
+        result::Result,
+        visa_direct::funds_transfer::{self, models::*},
+    },
+    blocking::VisaClient,
+    client::{models::RequestOptions, utils::MLETrait},
+};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`funds_transfer::FundsTransfer`].
+pub struct FundsTransfer<MLE>
+where
+    MLE: MLETrait,
+{
+    inner: funds_transfer::FundsTransfer<MLE>,
+    runtime: Arc<Runtime>,
+}
+
+impl<MLE> FundsTransfer<MLE>
+where
+    MLE: MLETrait,
+{
+    pub fn new(client: VisaClient<MLE>) -> Self {
+        FundsTransfer {
+            inner: funds_transfer::FundsTransfer::new(client.inner),
+            runtime: client.runtime,
+        }
+    }
+
+    /// See [`funds_transfer::FundsTransfer::with_request_options`].
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.inner = self.inner.with_request_options(options);
+        self
+    }
+
+    /// See [`funds_transfer::FundsTransfer::pull`].
+    pub fn pull(&self, payload: PullFundsRequest) -> Result<VisaResponse<FundsTransferResponse>> {
+        self.runtime.block_on(self.inner.pull(payload))
+    }
+
+    /// See [`funds_transfer::FundsTransfer::pull_status`].
+    pub fn pull_status(
+        &self,
+        status_identifier: &str,
+    ) -> Result<VisaResponse<FundsTransferResponse>> {
+        self.runtime
+            .block_on(self.inner.pull_status(status_identifier))
+    }
+
+    /// See [`funds_transfer::FundsTransfer::push`].
+    pub fn push(&self, payload: PushFundsRequest) -> Result<VisaResponse<FundsTransferResponse>> {
+        self.runtime.block_on(self.inner.push(payload))
+    }
+
+    /// See [`funds_transfer::FundsTransfer::push_status`].
+    pub fn push_status(
+        &self,
+        status_identifier: &str,
+    ) -> Result<VisaResponse<FundsTransferResponse>> {
+        self.runtime
+            .block_on(self.inner.push_status(status_identifier))
+    }
+
+    /// See [`funds_transfer::FundsTransfer::reverse`].
+    pub fn reverse(
+        &self,
+        payload: ReverseFundsRequest,
+    ) -> Result<VisaResponse<FundsTransferResponse>> {
+        self.runtime.block_on(self.inner.reverse(payload))
+    }
+
+    /// See [`funds_transfer::FundsTransfer::reverse_status`].
+    pub fn reverse_status(
+        &self,
+        status_identifier: &str,
+    ) -> Result<VisaResponse<FundsTransferResponse>> {
+        self.runtime
+            .block_on(self.inner.reverse_status(status_identifier))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{models::Config, MockVisaClient};
+    use http::response::Builder as ResponseBuilder;
+    use url::Url;
+
+    #[test]
+    fn test_funds_transfer_push_status() {
+        let mut mock_client = MockVisaClient::<()>::new();
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse("https://domain.test").unwrap());
+        mock_client
+            .expect_get_config()
+            .return_const(Config::default());
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|request, _| {
+                request.url().as_str()
+                    == "https://domain.test/visadirect/fundstransfer/v1/pushfundstransactions/234234322342343"
+            })
+            .returning(|_, _| {
+                Ok(ResponseBuilder::new()
+                    .status(200)
+                    .body(r#"{"transactionIdentifier": 381228649430015, "actionCode": "00"}"#)
+                    .unwrap()
+                    .into())
+            });
+
+        let funds_transfer = FundsTransfer::new(VisaClient::new(mock_client).unwrap());
+        let response = funds_transfer.push_status("234234322342343").unwrap();
+        assert!(response.is_approved());
+        assert_eq!(response.transaction_identifier, Some(381228649430015));
+    }
+}
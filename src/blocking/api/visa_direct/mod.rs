@@ -0,0 +1,11 @@
+//! Blocking version of [`api::visa_direct`][crate::api::visa_direct].
+
+pub mod funds_transfer;
+
+pub mod multi;
+
+pub mod payouts;
+
+pub mod query;
+
+pub mod reports;
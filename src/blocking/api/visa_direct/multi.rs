@@ -0,0 +1,158 @@
+//! Blocking version of
+//! [`api::visa_direct::multi`][crate::api::visa_direct::multi].
+
+use crate::{
+    api::visa_direct::{
+        funds_transfer::models::FundsTransferResponse,
+        multi::{self, models::*, MultiResult},
+    },
+    blocking::VisaClient,
+    client::{
+        models::{RequestOptions, RetryPolicy},
+        utils::MLETrait,
+    },
+};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`multi::MultiFundsTransfer`].
+pub struct MultiFundsTransfer<MLE>
+where
+    MLE: MLETrait,
+{
+    inner: multi::MultiFundsTransfer<MLE>,
+    runtime: Arc<Runtime>,
+}
+
+impl<MLE> MultiFundsTransfer<MLE>
+where
+    MLE: MLETrait,
+{
+    pub fn new(client: VisaClient<MLE>) -> Self {
+        MultiFundsTransfer {
+            inner: multi::MultiFundsTransfer::new(client.inner),
+            runtime: client.runtime,
+        }
+    }
+
+    /// See [`multi::MultiFundsTransfer::with_request_options`].
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.inner = self.inner.with_request_options(options);
+        self
+    }
+
+    /// See [`multi::MultiFundsTransfer::with_poll_policy`].
+    pub fn with_poll_policy(mut self, poll_policy: RetryPolicy) -> Self {
+        self.inner = self.inner.with_poll_policy(poll_policy);
+        self
+    }
+
+    /// See [`multi::MultiFundsTransfer::with_max_batch_size`].
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.inner = self.inner.with_max_batch_size(max_batch_size);
+        self
+    }
+
+    /// See [`multi::MultiFundsTransfer::submit`].
+    pub fn submit<T>(&self, transactions: &[T]) -> MultiResult<MultiHandle<'_, MLE>>
+    where
+        T: MultiTransaction,
+    {
+        Ok(MultiHandle {
+            inner: self.runtime.block_on(self.inner.submit(transactions))?,
+            runtime: &self.runtime,
+        })
+    }
+
+    /// See [`multi::MultiFundsTransfer::resume`].
+    pub fn resume(&self, kind: TransactionKind, chunks: Vec<MultiChunk>) -> MultiHandle<'_, MLE> {
+        MultiHandle {
+            inner: self.inner.resume(kind, chunks),
+            runtime: &self.runtime,
+        }
+    }
+}
+
+/// Blocking version of [`multi::MultiHandle`].
+pub struct MultiHandle<'a, MLE>
+where
+    MLE: MLETrait,
+{
+    inner: multi::MultiHandle<'a, MLE>,
+    runtime: &'a Runtime,
+}
+
+impl<'a, MLE> MultiHandle<'a, MLE>
+where
+    MLE: MLETrait,
+{
+    /// See [`multi::MultiHandle::kind`].
+    pub fn kind(&self) -> TransactionKind {
+        self.inner.kind()
+    }
+
+    /// See [`multi::MultiHandle::chunks`].
+    pub fn chunks(&self) -> &[MultiChunk] {
+        self.inner.chunks()
+    }
+
+    /// See [`multi::MultiHandle::wait`].
+    pub fn wait(self) -> MultiResult<Vec<Option<FundsTransferResponse>>> {
+        self.runtime.block_on(self.inner.wait())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{models::Config, MockVisaClient};
+    use http::response::Builder as ResponseBuilder;
+    use reqwest::Method;
+    use url::Url;
+
+    #[test]
+    fn test_multi_submit_and_wait() {
+        let mut mock_client = MockVisaClient::<()>::new();
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse("https://domain.test").unwrap());
+        mock_client
+            .expect_get_config()
+            .return_const(Config::default());
+        mock_client
+            .expect_execute_request_with_options()
+            .returning(|request, _| {
+                let body = match *request.method() {
+                    Method::POST => r#"{"statusIdentifier": "status-1"}"#,
+                    _ => r#"{"response": [{"actionCode": "00"}]}"#,
+                };
+                Ok(ResponseBuilder::new()
+                    .status(200)
+                    .body(body)
+                    .unwrap()
+                    .into())
+            });
+
+        let multi = MultiFundsTransfer::new(VisaClient::new(mock_client).unwrap());
+        let handle = multi
+            .submit::<crate::api::visa_direct::funds_transfer::models::PullFundsRequest>(&[])
+            .unwrap();
+        assert!(handle.chunks().is_empty());
+        assert_eq!(handle.wait().unwrap(), vec![]);
+
+        let handle = multi.resume(
+            TransactionKind::Pull,
+            vec![MultiChunk {
+                offset: 0,
+                status_identifier: "status-1".to_string(),
+                transactions: vec![TransactionReference {
+                    systems_trace_audit_number: 1,
+                    retrieval_reference_number: "412770000001".to_string(),
+                }],
+            }],
+        );
+        let results = handle.wait().unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].as_ref().unwrap().is_approved());
+    }
+}
@@ -0,0 +1,105 @@
+//! Blocking version of
+//! [`api::visa_direct::payouts`][crate::api::visa_direct::payouts].
+
+use crate::{
+    api::{
+        response::VisaResponse,
+        result::Result,
+        visa_direct::payouts::{self, models::*},
+    },
+    blocking::VisaClient,
+    client::{models::RequestOptions, state::WithMessageLevelEncryption},
+};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`payouts::Payouts`].
+pub struct Payouts {
+    inner: payouts::Payouts,
+    runtime: Arc<Runtime>,
+}
+
+impl Payouts {
+    pub fn new(client: VisaClient<WithMessageLevelEncryption>) -> Self {
+        Payouts {
+            inner: payouts::Payouts::new(client.inner),
+            runtime: client.runtime,
+        }
+    }
+
+    /// See [`payouts::Payouts::with_request_options`].
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.inner = self.inner.with_request_options(options);
+        self
+    }
+
+    /// See [`payouts::Payouts::send`].
+    pub fn send(&self, payload: PayoutRequest) -> Result<VisaResponse<PayoutResponse>> {
+        self.runtime.block_on(self.inner.send(payload))
+    }
+
+    /// See [`payouts::Payouts::validate`].
+    pub fn validate(
+        &self,
+        payload: PayoutRequest,
+    ) -> Result<VisaResponse<PayoutValidationResponse>> {
+        self.runtime.block_on(self.inner.validate(payload))
+    }
+
+    /// See [`payouts::Payouts::query`].
+    pub fn query(
+        &self,
+        initiating_party_id: i64,
+        payout_id: &PayoutId,
+    ) -> Result<VisaResponse<PayoutResponse>> {
+        self.runtime
+            .block_on(self.inner.query(initiating_party_id, payout_id))
+    }
+
+    /// See [`payouts::Payouts::cancel`].
+    pub fn cancel(&self, payload: CancelPayoutRequest) -> Result<VisaResponse<PayoutResponse>> {
+        self.runtime.block_on(self.inner.cancel(payload))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{models::Config, MockVisaClient};
+    use http::response::Builder as ResponseBuilder;
+    use url::Url;
+
+    #[test]
+    fn test_payouts_query() {
+        let mut mock_client = MockVisaClient::<WithMessageLevelEncryption>::new();
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse("https://domain.test").unwrap());
+        mock_client
+            .expect_get_config()
+            .return_const(Config::default());
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|request, _| {
+                request.url().as_str()
+                    == "https://domain.test/visapayouts/v3/payouts?initiatingPartyId=1002&transactionId=431234567890123"
+            })
+            .returning(|_, _| {
+                Ok(ResponseBuilder::new()
+                    .status(200)
+                    .body(r#"{"transactionDetail": {"transactionId": 431234567890123, "status": "SUCCESS"}}"#)
+                    .unwrap()
+                    .into())
+            });
+
+        let payouts = Payouts::new(VisaClient::new(mock_client).unwrap());
+        let response = payouts
+            .query(1002, &PayoutId::TransactionId(431234567890123))
+            .unwrap();
+
+        assert_eq!(
+            response.transaction_detail.status,
+            Some(PayoutStatus::Success)
+        );
+    }
+}
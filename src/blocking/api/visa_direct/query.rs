@@ -0,0 +1,102 @@
+//! Blocking version of
+//! [`api::visa_direct::query`][crate::api::visa_direct::query].
+
+use crate::{
+    api::{
+        response::VisaResponse,
+        result::Result,
+        visa_direct::{
+            funds_transfer::models::PushFundsRequest,
+            query::{self, models::*},
+        },
+    },
+    blocking::VisaClient,
+    client::{models::RequestOptions, utils::MLETrait},
+};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`query::TransactionQuery`].
+pub struct TransactionQuery<MLE>
+where
+    MLE: MLETrait,
+{
+    inner: query::TransactionQuery<MLE>,
+    runtime: Arc<Runtime>,
+}
+
+impl<MLE> TransactionQuery<MLE>
+where
+    MLE: MLETrait,
+{
+    pub fn new(client: VisaClient<MLE>) -> Self {
+        TransactionQuery {
+            inner: query::TransactionQuery::new(client.inner),
+            runtime: client.runtime,
+        }
+    }
+
+    /// See [`query::TransactionQuery::with_request_options`].
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.inner = self.inner.with_request_options(options);
+        self
+    }
+
+    /// See [`query::TransactionQuery::query`].
+    pub fn query(&self, filter: &QueryFilter, start_index: u32) -> Result<VisaResponse<QueryPage>> {
+        self.runtime.block_on(self.inner.query(filter, start_index))
+    }
+
+    /// See [`query::TransactionQuery::query_all`].
+    pub fn query_all(&self, filter: &QueryFilter) -> Result<Vec<TransactionRecord>> {
+        self.runtime.block_on(self.inner.query_all(filter))
+    }
+
+    /// See [`query::TransactionQuery::reconcile_push`].
+    pub fn reconcile_push(&self, payload: &PushFundsRequest) -> Result<PushReconciliation> {
+        self.runtime.block_on(self.inner.reconcile_push(payload))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{models::Config, MockVisaClient};
+    use http::response::Builder as ResponseBuilder;
+    use url::Url;
+
+    #[test]
+    fn test_transaction_query_query_all() {
+        let mut mock_client = MockVisaClient::<()>::new();
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse("https://domain.test").unwrap());
+        mock_client
+            .expect_get_config()
+            .return_const(Config::default());
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|request, _| {
+                request.url().as_str()
+                    == "https://domain.test/visadirect/v1/transactionquery?acquiringBIN=408999&transactionIdentifier=381228649430015"
+            })
+            .returning(|_, _| {
+                Ok(ResponseBuilder::new()
+                    .status(200)
+                    .body(r#"{"transactions": [{"transactionIdentifier": 381228649430015, "actionCode": "00"}], "totalCount": 1}"#)
+                    .unwrap()
+                    .into())
+            });
+
+        let query = TransactionQuery::new(VisaClient::new(mock_client).unwrap());
+        let filter = QueryFilterBuilder::default()
+            .acquiring_bin(408999)
+            .transaction_identifier(Some(381228649430015))
+            .build()
+            .unwrap();
+
+        let records = query.query_all(&filter).unwrap();
+        assert_eq!(records.len(), 1);
+        assert!(records[0].is_approved());
+    }
+}
@@ -0,0 +1,175 @@
+//! Blocking version of
+//! [`api::visa_direct::reports`][crate::api::visa_direct::reports].
+
+use crate::{
+    api::{
+        response::VisaResponse,
+        result::{Error, Result},
+        visa_direct::reports::{self, models::*},
+    },
+    blocking::VisaClient,
+    client::{models::RequestOptions, utils::MLETrait},
+};
+use serde::de::DeserializeOwned;
+use std::{io::Write, sync::Arc};
+use tokio::runtime::Runtime;
+
+/// Blocking version of [`reports::Reports`].
+pub struct Reports<MLE>
+where
+    MLE: MLETrait,
+{
+    inner: reports::Reports<MLE>,
+    runtime: Arc<Runtime>,
+}
+
+impl<MLE> Reports<MLE>
+where
+    MLE: MLETrait,
+{
+    pub fn new(client: VisaClient<MLE>) -> Self {
+        Reports {
+            inner: reports::Reports::new(client.inner),
+            runtime: client.runtime,
+        }
+    }
+
+    /// See [`reports::Reports::with_request_options`].
+    pub fn with_request_options(mut self, options: RequestOptions) -> Self {
+        self.inner = self.inner.with_request_options(options);
+        self
+    }
+
+    /// See [`reports::Reports::list`].
+    pub fn list(&self, filter: &ReportFilter) -> Result<VisaResponse<ReportList>> {
+        self.runtime.block_on(self.inner.list(filter))
+    }
+
+    /// See [`reports::Reports::request`].
+    pub fn request(&self, payload: ReportRequest) -> Result<VisaResponse<ReportMetadata>> {
+        self.runtime.block_on(self.inner.request(payload))
+    }
+
+    /// See [`reports::Reports::get`].
+    pub fn get(&self, report_id: &str) -> Result<VisaResponse<ReportMetadata>> {
+        self.runtime.block_on(self.inner.get(report_id))
+    }
+
+    /// See [`reports::Reports::download`].
+    pub fn download(&self, report_id: &str) -> Result<ReportDownload> {
+        let inner = self.runtime.block_on(self.inner.download(report_id))?;
+        Ok(ReportDownload {
+            inner,
+            runtime: self.runtime.clone(),
+        })
+    }
+}
+
+/// Blocking version of [`reports::ReportDownload`].
+pub struct ReportDownload {
+    inner: reports::ReportDownload,
+    runtime: Arc<Runtime>,
+}
+
+impl ReportDownload {
+    /// See [`reports::ReportDownload::format`].
+    pub fn format(&self) -> ReportFormat {
+        self.inner.format()
+    }
+
+    /// See [`reports::ReportDownload::content_length`].
+    pub fn content_length(&self) -> Option<u64> {
+        self.inner.content_length()
+    }
+
+    /// See [`reports::ReportDownload::correlation_id`].
+    pub fn correlation_id(&self) -> Option<&str> {
+        self.inner.correlation_id()
+    }
+
+    /// Writes the rest of the report to `writer` as it is received, and
+    /// returns the number of bytes written. Same as
+    /// [`reports::ReportDownload::copy_to`], for a blocking writer, e.g. a
+    /// [`File`][std::fs::File].
+    pub fn copy_to<W: Write>(mut self, writer: &mut W) -> Result<u64> {
+        let buffered = self.inner.take_buffered();
+        writer.write_all(&buffered).map_err(Error::custom)?;
+        let mut written = buffered.len() as u64;
+
+        while let Some(chunk) = self.runtime.block_on(self.inner.next_chunk())? {
+            let chunk = chunk.as_ref();
+            writer.write_all(chunk).map_err(Error::custom)?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().map_err(Error::custom)?;
+        Ok(written)
+    }
+
+    /// See [`reports::ReportDownload::next_record`].
+    pub fn next_record<T: DeserializeOwned>(&mut self) -> Result<Option<T>> {
+        self.runtime.block_on(self.inner.next_record())
+    }
+
+    /// See [`reports::ReportDownload::records`].
+    pub fn records<T: DeserializeOwned>(self) -> Result<Vec<T>> {
+        self.runtime.block_on(self.inner.records())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{models::Config, MockVisaClient};
+    use http::response::Builder as ResponseBuilder;
+    use url::Url;
+
+    fn mock_download(body: &'static str) -> Reports<()> {
+        let mut mock_client = MockVisaClient::<()>::new();
+        mock_client
+            .expect_get_base_url()
+            .return_const(Url::parse("https://domain.test").unwrap());
+        mock_client
+            .expect_get_config()
+            .return_const(Config::default());
+        mock_client
+            .expect_execute_request_with_options()
+            .withf(|request, _| {
+                request.url().as_str()
+                    == "https://domain.test/visadirect/reports/v1/reports/RPT-20240108-0001/content"
+            })
+            .returning(move |_, _| {
+                Ok(ResponseBuilder::new()
+                    .status(200)
+                    .header("content-type", "text/csv")
+                    .body(body.to_string())
+                    .unwrap()
+                    .into())
+            });
+        Reports::new(VisaClient::new(mock_client).unwrap())
+    }
+
+    #[test]
+    fn test_reports_download() {
+        let reports = mock_download("transactionIdentifier,stan\n381228649430015,451000\n");
+        let mut download = reports.download("RPT-20240108-0001").unwrap();
+
+        let record = download.next_record::<SettlementRecord>().unwrap().unwrap();
+        assert_eq!(record.transaction_identifier, Some(381228649430015));
+        assert!(download
+            .next_record::<SettlementRecord>()
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_reports_download_copy_to() {
+        let body = "transactionIdentifier,stan\n381228649430015,451000\n";
+        let reports = mock_download(body);
+        let download = reports.download("RPT-20240108-0001").unwrap();
+        let mut file = Vec::new();
+        let written = download.copy_to(&mut file).unwrap();
+
+        assert_eq!(written, body.len() as u64);
+        assert_eq!(file, body.as_bytes());
+    }
+}
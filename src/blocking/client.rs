@@ -0,0 +1,101 @@
+use crate::{
+    api::result::Result,
+    client::{
+        models::{Config, RequestOptions},
+        utils::MLETrait,
+        ClientBuildResult,
+    },
+};
+use mockall_double::double;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+use url::Url;
+
+#[double]
+use crate::client::VisaClient as AsyncVisaClient;
+
+/// Blocking version of [`VisaClient`][crate::client::VisaClient]. Cheap to
+/// clone: the clones share the runtime.
+#[derive(Clone)]
+pub struct VisaClient<MLE>
+where
+    MLE: MLETrait,
+{
+    pub(crate) inner: AsyncVisaClient<MLE>,
+    pub(crate) runtime: Arc<Runtime>,
+}
+
+impl<MLE> VisaClient<MLE>
+where
+    MLE: MLETrait,
+{
+    /// Wraps a client built with
+    /// [`VisaClientBuilder`][crate::client::VisaClientBuilder], and starts
+    /// the runtime driving it.
+    pub fn new(client: AsyncVisaClient<MLE>) -> ClientBuildResult<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        Ok(VisaClient {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    pub fn get_config(&self) -> &Config {
+        self.inner.get_config()
+    }
+
+    /// See [`VisaClient::get_base_url`][crate::client::VisaClient::get_base_url].
+    pub fn get_base_url(&self) -> Url {
+        self.inner.get_base_url()
+    }
+
+    /// See [`VisaClient::execute_request`][crate::client::VisaClient::execute_request].
+    pub fn execute_request(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        self.runtime.block_on(self.inner.execute_request(request))
+    }
+
+    /// See
+    /// [`VisaClient::execute_request_with_options`][crate::client::VisaClient::execute_request_with_options].
+    pub fn execute_request_with_options(
+        &self,
+        request: reqwest::Request,
+        options: &RequestOptions,
+    ) -> Result<reqwest::Response> {
+        self.runtime
+            .block_on(self.inner.execute_request_with_options(request, options))
+    }
+
+    /// Reads the body of a response returned by
+    /// [`execute_request`][Self::execute_request].
+    pub fn read_body(&self, response: reqwest::Response) -> Result<Vec<u8>> {
+        Ok(self.runtime.block_on(response.bytes())?.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::response::Builder as ResponseBuilder;
+    use reqwest::{Method, Request};
+
+    #[test]
+    fn test_execute_request() {
+        let mut mock_client = AsyncVisaClient::<()>::new();
+        mock_client.expect_execute_request().returning(|_| {
+            Ok(ResponseBuilder::new()
+                .status(200)
+                .body("pong")
+                .unwrap()
+                .into())
+        });
+
+        let client = VisaClient::new(mock_client).unwrap();
+        let request = Request::new(Method::GET, Url::parse("https://domain.test/").unwrap());
+        let response = client.execute_request(request).unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(client.read_body(response).unwrap(), b"pong");
+    }
+}
@@ -0,0 +1,33 @@
+//! # Blocking Client
+//!
+//! A synchronous facade over [`VisaClient`][crate::client::VisaClient] and the
+//! API modules, for callers without an async runtime, e.g. batch jobs.
+//! Requires the `blocking` feature.
+//!
+//! The blocking client drives the async one on a private, current-thread Tokio
+//! runtime, so that the models, errors, retries and Message Level Encryption
+//! are shared. It must not be used from within an async runtime.
+//!
+//! ## Example
+//! ```no_run
+//! use visa_sdk::{
+//!     blocking::{api::hello_world::HelloWorld, VisaClient},
+//!     client::{models::MutualTls, VisaClientBuilder},
+//! };
+//!
+//! # fn example(mtls: MutualTls) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = VisaClientBuilder::new().set_mutual_tls(mtls).build()?;
+//! let client = VisaClient::new(client)?;
+//!
+//! let response = HelloWorld::new(client).get()?;
+//! println!("{}", response.message);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod api;
+
+#[allow(clippy::module_inception)]
+mod client;
+
+pub use client::*;
@@ -0,0 +1,66 @@
+use derive_more::From;
+use std::time::SystemTime;
+
+pub type ClientBuildResult<T> = core::result::Result<T, ClientBuildError>;
+
+/// Errors returned by [`VisaClientBuilder::build`][super::VisaClientBuilder::build]
+/// when the supplied credentials cannot be turned into a client.
+#[derive(Debug, From)]
+pub enum ClientBuildError {
+    /// The certificate is not a valid PKCS#12 archive.
+    InvalidPkcs12(String),
+
+    /// The PKCS#12 archive, or the encrypted PEM private key, could not be
+    /// opened with the supplied passphrase.
+    WrongPassphrase,
+
+    /// The PKCS#12 archive does not contain a private key.
+    MissingPrivateKey,
+
+    /// The PKCS#12 archive, or the PEM certificate chain, does not contain a
+    /// certificate.
+    MissingCertificate,
+
+    /// The PEM private key could not be parsed.
+    InvalidPrivateKey(String),
+
+    /// The private key does not belong to the client certificate.
+    KeyMismatch,
+
+    /// The client certificate could not be parsed.
+    InvalidCertificate(String),
+
+    /// The client certificate expired at `not_after`.
+    ExpiredCertificate { not_after: SystemTime },
+
+    /// The client certificate is not valid before `not_before`.
+    CertificateNotYetValid { not_before: SystemTime },
+
+    /// A root certificate added to the builder could not be loaded.
+    InvalidRootCertificate(String),
+
+    /// The [`VisaClientConfig`][super::models::VisaClientConfig] could not be
+    /// parsed, or is incomplete.
+    InvalidConfig(String),
+
+    // -- Externals
+    /// A root certificate file or directory could not be read.
+    #[from]
+    Io(std::io::Error),
+
+    /// The TLS backend rejected the identity, or failed to initialize.
+    #[from]
+    TlsBackend(reqwest::Error),
+}
+
+// region:    --- Error Boilerplate
+
+impl core::fmt::Display for ClientBuildError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::result::Result<(), core::fmt::Error> {
+        write!(fmt, "{self:?}")
+    }
+}
+
+impl std::error::Error for ClientBuildError {}
+
+// endregion: --- Error Boilerplate
@@ -1,6 +1,16 @@
+use std::time::Duration;
+use url::Url;
+
 use super::{
-    models::{ApiLevel, Config, MutualTls},
-    state::{self, WithMutualTls},
+    build_result::ClientBuildResult,
+    identity,
+    models::{
+        ApiLevel, Config, MessageLevelEncryption, MutualTls, RetryPolicy, RootCertificates,
+        VdpPrefix, VisaClientConfig, XPayToken,
+    },
+    roots::TrustConfig,
+    state::{self, WithMessageLevelEncryption, WithMutualTls, WithXPayToken},
+    timeouts::Timeouts,
     VisaClient,
 };
 
@@ -13,6 +23,11 @@ where
     mutual_tls: MTLS,
     message_level_encryption: MLE,
     api_level: ApiLevel,
+    base_url: Option<Url>,
+    vdp_prefix: VdpPrefix,
+    trust: TrustConfig,
+    timeouts: Timeouts,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl VisaClientBuilder<state::WithoutMutualTls, state::WithoutMessageLevelEncryption> {
@@ -21,6 +36,28 @@ impl VisaClientBuilder<state::WithoutMutualTls, state::WithoutMessageLevelEncryp
             ..VisaClientBuilder::default()
         }
     }
+
+    /// A builder with the settings and the mutual TLS credentials of
+    /// `config`, see [`apply_config`][Self::apply_config]. Message Level
+    /// Encryption is left to the caller, see
+    /// [`VisaClientConfig::message_level_encryption`].
+    pub fn from_config(
+        config: &VisaClientConfig,
+    ) -> ClientBuildResult<
+        VisaClientBuilder<state::WithMutualTls, state::WithoutMessageLevelEncryption>,
+    > {
+        Ok(Self::new()
+            .apply_config(config)?
+            .set_mutual_tls(config.mutual_tls()?))
+    }
+
+    /// Same as [`from_config`][Self::from_config], with the configuration
+    /// read from environment variables, see [`VisaClientConfig::from_env`].
+    pub fn from_env() -> ClientBuildResult<
+        VisaClientBuilder<state::WithMutualTls, state::WithoutMessageLevelEncryption>,
+    > {
+        Self::from_config(&VisaClientConfig::from_env()?)
+    }
 }
 
 /// Final state of the builder. All APIs require mutual TLS, but only some of
@@ -29,44 +66,40 @@ impl<MLE> VisaClientBuilder<state::WithMutualTls, MLE>
 where
     MLE: state::MessageLevelEncryptionState + Clone,
 {
-    pub fn build(&self) -> VisaClient<MLE> {
-        VisaClient {
-            mutual_tls: self.mutual_tls.0.clone(),
+    /// Builds the client.
+    ///
+    /// The PKCS#12 certificate of the [`MutualTls`] configuration is checked
+    /// first: an invalid archive, a wrong passphrase, a missing private key
+    /// or an expired certificate is reported as a
+    /// [`ClientBuildError`][super::ClientBuildError].
+    pub fn build(&self) -> ClientBuildResult<VisaClient<MLE>> {
+        let certificate_identity = identity::load_identity(&self.mutual_tls.0)?;
+
+        Ok(VisaClient {
+            authentication: self.mutual_tls.clone(),
             message_level_encryption: self.message_level_encryption.clone(),
             config: self.build_api_config(),
-            _client: self.build_reqwest(),
-        }
+            retry_policy: self.retry_policy.clone(),
+            _client: self.build_reqwest(Some(certificate_identity))?,
+        })
     }
+}
 
-    fn build_reqwest(&self) -> reqwest::Client {
-        let certificate_identity = reqwest::Identity::from_pkcs12_der(
-            &self.mutual_tls.0.cert,
-            match &self.mutual_tls.0.cert_key {
-                Some(cert_key) => cert_key,
-                None => "",
-            },
-        )
-        .unwrap();
-
-        reqwest::Client::builder()
-            .identity(certificate_identity)
-            .default_headers(Self::build_headers())
-            .build()
-            .unwrap()
-    }
-
-    fn build_headers() -> reqwest::header::HeaderMap {
-        let mut headers = reqwest::header::HeaderMap::new();
-
-        let version = env!("CARGO_PKG_VERSION");
-        let user_agent = format!("visa_sdk/v{}", version);
-
-        headers.append(
-            reqwest::header::USER_AGENT,
-            reqwest::header::HeaderValue::from_str(user_agent.as_str()).unwrap(),
-        );
-
-        headers
+/// Final state of the builder, for the APIs authenticating with an
+/// X-Pay-Token over one-way TLS.
+impl<MLE> VisaClientBuilder<state::WithXPayToken, MLE>
+where
+    MLE: state::MessageLevelEncryptionState + Clone,
+{
+    /// Builds the client.
+    pub fn build(&self) -> ClientBuildResult<VisaClient<MLE, state::WithXPayToken>> {
+        Ok(VisaClient {
+            authentication: self.mutual_tls.clone(),
+            message_level_encryption: self.message_level_encryption.clone(),
+            config: self.build_api_config(),
+            retry_policy: self.retry_policy.clone(),
+            _client: self.build_reqwest(None)?,
+        })
     }
 }
 
@@ -83,17 +116,44 @@ where
             mutual_tls: WithMutualTls(mutual_tls),
             message_level_encryption: self.message_level_encryption,
             api_level: self.api_level,
+            base_url: self.base_url,
+            vdp_prefix: self.vdp_prefix,
+            trust: self.trust,
+            timeouts: self.timeouts,
+            retry_policy: self.retry_policy,
+        }
+    }
+
+    /// Authenticates with an X-Pay-Token instead of Mutual TLS.
+    pub fn set_x_pay_token(
+        self,
+        x_pay_token: XPayToken,
+    ) -> VisaClientBuilder<state::WithXPayToken, MLE> {
+        VisaClientBuilder {
+            mutual_tls: WithXPayToken(x_pay_token),
+            message_level_encryption: self.message_level_encryption,
+            api_level: self.api_level,
+            base_url: self.base_url,
+            vdp_prefix: self.vdp_prefix,
+            trust: self.trust,
+            timeouts: self.timeouts,
+            retry_policy: self.retry_policy,
         }
     }
 
     pub fn set_message_level_encryption(
         self,
-        message_level_encryption: state::WithMessageLevelEncryption,
+        message_level_encryption: MessageLevelEncryption,
     ) -> VisaClientBuilder<MTLS, state::WithMessageLevelEncryption> {
         VisaClientBuilder {
-            message_level_encryption,
+            message_level_encryption: WithMessageLevelEncryption(message_level_encryption),
             mutual_tls: self.mutual_tls,
             api_level: self.api_level,
+            base_url: self.base_url,
+            vdp_prefix: self.vdp_prefix,
+            trust: self.trust,
+            timeouts: self.timeouts,
+            retry_policy: self.retry_policy,
         }
     }
 
@@ -101,9 +161,423 @@ where
         VisaClientBuilder { api_level, ..self }
     }
 
+    /// Overrides the base URL of the API level, e.g. to route through an
+    /// egress proxy, or to a local stand-in. A path of the URL is kept, and
+    /// the API paths are appended to it. The API level still applies to the
+    /// path prefixes, see [`set_vdp_prefix`][Self::set_vdp_prefix].
+    pub fn set_base_url(self, base_url: Url) -> Self {
+        VisaClientBuilder {
+            base_url: Some(base_url),
+            ..self
+        }
+    }
+
+    /// Overrides whether the APIs served under `/vdp` use the prefix. By
+    /// default, they do outside of production.
+    pub fn set_vdp_prefix(self, vdp_prefix: VdpPrefix) -> Self {
+        VisaClientBuilder { vdp_prefix, ..self }
+    }
+
+    /// Trusts extra root certificates, e.g. a CA bundle downloaded from
+    /// Visa's developer portal. Can be called several times.
+    pub fn add_root_certificates(mut self, root_certificates: RootCertificates) -> Self {
+        self.trust.root_certificates.push(root_certificates);
+        self
+    }
+
+    /// Whether the system root certificates are trusted. Enabled by default.
+    pub fn set_system_roots(mut self, enabled: bool) -> Self {
+        self.trust.disable_system_roots = !enabled;
+        self
+    }
+
+    /// Only accepts server certificates chaining to the Visa DigiCert and
+    /// Entrust roots, bundled with this crate. The system roots are not
+    /// trusted while pinning is enabled; root certificates added with
+    /// [`add_root_certificates`][Self::add_root_certificates] still are.
+    pub fn set_visa_root_pinning(mut self, enabled: bool) -> Self {
+        self.trust.pin_visa_roots = enabled;
+        self
+    }
+
+    /// Applies the settings of `config`: the API level, the base URL, the
+    /// `/vdp` prefix, the trust settings, the timeouts and the retry policy.
+    /// The credentials and keys are not applied, see
+    /// [`VisaClientConfig::mutual_tls`], [`VisaClientConfig::x_pay_token`] and
+    /// [`VisaClientConfig::message_level_encryption`].
+    pub fn apply_config(mut self, config: &VisaClientConfig) -> ClientBuildResult<Self> {
+        self.api_level = config.api_level;
+        self.base_url = config.base_url.clone();
+        self.vdp_prefix = config.vdp_prefix;
+        self.trust
+            .root_certificates
+            .extend(config.root_certificates());
+        self.trust.disable_system_roots = !config.tls.system_roots;
+        self.trust.pin_visa_roots = config.tls.visa_root_pinning;
+        self.timeouts.connect = config.timeouts.connect_ms.map(Duration::from_millis);
+        self.timeouts.read = config.timeouts.read_ms.map(Duration::from_millis);
+        self.timeouts.total = config.timeouts.total_ms.map(Duration::from_millis);
+        self.retry_policy = config.retry_policy()?;
+        Ok(self)
+    }
+
+    /// Timeout for establishing the connection, TLS handshake included.
+    pub fn set_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.timeouts.connect = Some(timeout);
+        self
+    }
+
+    /// Timeout for each read of the response, reset after every successful
+    /// read.
+    pub fn set_read_timeout(mut self, timeout: Duration) -> Self {
+        self.timeouts.read = Some(timeout);
+        self
+    }
+
+    /// Total timeout of a call, from connecting until the response body is
+    /// read. Can be overridden per call, see
+    /// [`RequestOptions`][super::models::RequestOptions]. Applies to each
+    /// attempt when retrying.
+    pub fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.timeouts.total = Some(timeout);
+        self
+    }
+
+    /// Retries the failed idempotent calls, see [`RetryPolicy`]. Disabled by
+    /// default.
+    pub fn set_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        VisaClientBuilder {
+            retry_policy: Some(retry_policy),
+            ..self
+        }
+    }
+
     fn build_api_config(&self) -> Config {
         Config {
             api_level: self.api_level,
+            base_url: self.base_url.clone(),
+            vdp_prefix: self.vdp_prefix,
         }
     }
+
+    fn build_reqwest(
+        &self,
+        certificate_identity: Option<reqwest::Identity>,
+    ) -> ClientBuildResult<reqwest::Client> {
+        let mut builder = self
+            .timeouts
+            .apply(reqwest::Client::builder().default_headers(Self::build_headers()));
+        if let Some(certificate_identity) = certificate_identity {
+            builder = builder.identity(certificate_identity);
+        }
+
+        // The identity is loaded for the rustls backend when the feature is
+        // enabled, even if native-tls is enabled as well.
+        #[cfg(feature = "rustls")]
+        let builder = builder.use_rustls_tls();
+
+        Ok(self.trust.apply(builder)?.build()?)
+    }
+
+    fn build_headers() -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+
+        headers.append(
+            reqwest::header::USER_AGENT,
+            reqwest::header::HeaderValue::from_static(concat!(
+                "visa_sdk/v",
+                env!("CARGO_PKG_VERSION")
+            )),
+        );
+
+        headers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{
+        identity::tests::{TEST_CERT_PEM, TEST_KEY_PEM, TEST_PKCS12, TEST_PKCS12_PASS},
+        models::MutualTlsBuilder,
+        testkit::{spawn_tls_server, RESPONSE_BODY, TEST_CA_PEM},
+        ClientBuildError,
+    };
+    use reqwest::{Method, Request};
+    use std::path::Path;
+
+    fn mutual_tls(cert_key: &str) -> MutualTls {
+        MutualTlsBuilder::default()
+            .user_id("user")
+            .password("password")
+            .cert(TEST_PKCS12.to_vec())
+            .cert_key(Some(cert_key.to_string()))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_build() {
+        let client = VisaClientBuilder::new()
+            .set_mutual_tls(mutual_tls(TEST_PKCS12_PASS))
+            .set_api_level(ApiLevel::Production)
+            .build()
+            .expect("Failed to build VisaClient");
+
+        assert_eq!(client.config.api_level, ApiLevel::Production);
+    }
+
+    #[test]
+    fn test_build_wrong_passphrase() {
+        let client = VisaClientBuilder::new()
+            .set_mutual_tls(mutual_tls("wrong"))
+            .build();
+
+        assert!(matches!(client, Err(ClientBuildError::WrongPassphrase)));
+    }
+
+    #[test]
+    fn test_build_pem() {
+        let mutual_tls = MutualTlsBuilder::default()
+            .user_id("user")
+            .password("password")
+            .pem(TEST_CERT_PEM, TEST_KEY_PEM)
+            .build()
+            .unwrap();
+
+        let client = VisaClientBuilder::new().set_mutual_tls(mutual_tls).build();
+
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn test_mutual_tls_requires_one_certificate() {
+        let neither = MutualTlsBuilder::default()
+            .user_id("user")
+            .password("password")
+            .build();
+        assert!(matches!(
+            neither,
+            Err(crate::utils::BuilderError::ValidationViolition(_))
+        ));
+
+        let both = MutualTlsBuilder::default()
+            .user_id("user")
+            .password("password")
+            .cert(TEST_PKCS12.to_vec())
+            .pem(TEST_CERT_PEM, TEST_KEY_PEM)
+            .build();
+        assert!(matches!(
+            both,
+            Err(crate::utils::BuilderError::ValidationViolition(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_with_root_certificates() {
+        let client = VisaClientBuilder::new()
+            .add_root_certificates(RootCertificates::Pem(TEST_CERT_PEM.as_bytes().to_vec()))
+            .set_system_roots(false)
+            .set_visa_root_pinning(true)
+            .set_mutual_tls(mutual_tls(TEST_PKCS12_PASS))
+            .build();
+
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn test_build_with_invalid_root_certificates() {
+        let client = VisaClientBuilder::new()
+            .set_mutual_tls(mutual_tls(TEST_PKCS12_PASS))
+            .add_root_certificates(RootCertificates::Der(b"not a certificate".to_vec()))
+            .build();
+
+        assert!(matches!(
+            client,
+            Err(ClientBuildError::InvalidRootCertificate(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_with_base_url() {
+        let client = VisaClientBuilder::new()
+            .set_mutual_tls(mutual_tls(TEST_PKCS12_PASS))
+            .set_api_level(ApiLevel::Production)
+            .set_base_url(Url::parse("https://proxy.internal/visa").unwrap())
+            .set_vdp_prefix(VdpPrefix::Always)
+            .build()
+            .expect("Failed to build VisaClient");
+
+        assert_eq!(
+            client.get_base_url().as_str(),
+            "https://proxy.internal/visa/"
+        );
+        assert!(client.get_config().uses_vdp_prefix());
+    }
+
+    #[test]
+    fn test_from_config() {
+        let config = VisaClientConfig::from_file(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("src/client/testdata/config.toml"),
+        )
+        .unwrap();
+        let mle = config.message_level_encryption().unwrap().unwrap();
+
+        let client = VisaClientBuilder::from_config(&config)
+            .unwrap()
+            .set_message_level_encryption(mle)
+            .build()
+            .expect("Failed to build VisaClient");
+
+        assert_eq!(client.config.api_level, ApiLevel::Certification);
+        assert_eq!(client.get_base_url().as_str(), "https://proxy.test/visa/");
+        assert!(client.get_config().uses_vdp_prefix());
+        assert_eq!(client.retry_policy.unwrap().max_retries, 5);
+    }
+
+    #[test]
+    fn test_from_config_without_mutual_tls() {
+        let config = VisaClientConfig::from_toml(r#"api_level = "production""#).unwrap();
+
+        assert!(matches!(
+            VisaClientBuilder::from_config(&config),
+            Err(ClientBuildError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_with_timeouts_and_retry_policy() {
+        let client = VisaClientBuilder::new()
+            .set_mutual_tls(mutual_tls(TEST_PKCS12_PASS))
+            .set_connect_timeout(Duration::from_secs(5))
+            .set_read_timeout(Duration::from_secs(10))
+            .set_timeout(Duration::from_secs(30))
+            .set_retry_policy(RetryPolicy::default())
+            .build()
+            .expect("Failed to build VisaClient");
+
+        assert_eq!(client.retry_policy.unwrap().max_retries, 3);
+    }
+
+    /// Builds a client for the stand-in at `url`, trusting the test CA only.
+    fn tls_client(
+        url: &Url,
+        mutual_tls: MutualTls,
+    ) -> VisaClient<state::WithoutMessageLevelEncryption> {
+        VisaClientBuilder::new()
+            .set_base_url(url.clone())
+            .add_root_certificates(RootCertificates::Pem(TEST_CA_PEM.as_bytes().to_vec()))
+            .set_system_roots(false)
+            .set_mutual_tls(mutual_tls)
+            .build()
+            .expect("Failed to build VisaClient")
+    }
+
+    async fn get(
+        client: &VisaClient<state::WithoutMessageLevelEncryption>,
+    ) -> crate::api::result::Result<String> {
+        let url = client.get_base_url().join("vdp/helloworld").unwrap();
+        let request = Request::new(Method::GET, url);
+        let response = client.execute_request(request).await?;
+        assert_eq!(response.status(), 200);
+        Ok(response.text().await?)
+    }
+
+    #[tokio::test]
+    async fn test_mutual_tls_handshake_pkcs12() {
+        let url = spawn_tls_server().await;
+        let client = tls_client(&url, mutual_tls(TEST_PKCS12_PASS));
+
+        assert_eq!(get(&client).await.unwrap(), RESPONSE_BODY);
+    }
+
+    #[tokio::test]
+    async fn test_mutual_tls_handshake_pem() {
+        let url = spawn_tls_server().await;
+        let client = tls_client(
+            &url,
+            MutualTlsBuilder::default()
+                .user_id("user")
+                .password("password")
+                .pem(TEST_CERT_PEM, TEST_KEY_PEM)
+                .build()
+                .unwrap(),
+        );
+
+        assert_eq!(get(&client).await.unwrap(), RESPONSE_BODY);
+    }
+
+    #[tokio::test]
+    async fn test_mutual_tls_handshake_untrusted_client_certificate() {
+        let url = spawn_tls_server().await;
+        // The MLE test certificate is not issued by the test CA.
+        let client = tls_client(
+            &url,
+            MutualTlsBuilder::default()
+                .user_id("user")
+                .password("password")
+                .pem(
+                    crate::client::mle::tests::TEST_CERT,
+                    crate::client::mle::tests::TEST_KEY,
+                )
+                .build()
+                .unwrap(),
+        );
+
+        assert!(get(&client).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mutual_tls_handshake_visa_root_pinning() {
+        let url = spawn_tls_server().await;
+        let client = VisaClientBuilder::new()
+            .set_base_url(url)
+            .set_visa_root_pinning(true)
+            .set_mutual_tls(mutual_tls(TEST_PKCS12_PASS))
+            .build()
+            .expect("Failed to build VisaClient");
+
+        // The stand-in does not chain to the Visa roots.
+        assert!(get(&client).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_x_pay_token_client() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/vdp/helloworld")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "apikey".into(),
+                "api-key-from-visa".into(),
+            ))
+            .match_header(
+                "x-pay-token",
+                mockito::Matcher::Regex("^xv2:[0-9]+:[0-9a-f]{64}$".into()),
+            )
+            .match_header("authorization", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_body(RESPONSE_BODY)
+            .create_async()
+            .await;
+
+        let client = VisaClientBuilder::new()
+            .set_x_pay_token(
+                crate::client::models::XPayTokenBuilder::default()
+                    .api_key("api-key-from-visa")
+                    .shared_secret("shared-secret-from-visa")
+                    .build()
+                    .unwrap(),
+            )
+            .set_base_url(server.url().parse().unwrap())
+            .build()
+            .expect("Failed to build VisaClient");
+        let url = client.get_base_url().join("vdp/helloworld").unwrap();
+        let response = client
+            .execute_request(Request::new(Method::GET, url))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        mock.assert_async().await;
+    }
 }
@@ -1,9 +1,20 @@
+mod build_result;
 mod builder;
+#[allow(clippy::module_inception)]
 mod client;
+pub(crate) mod identity;
+pub(crate) mod mle;
+pub(crate) mod retry;
+pub(crate) mod roots;
+#[cfg(test)]
+mod testkit;
+pub(crate) mod timeouts;
+pub(crate) mod x_pay_token;
 
 // TODO: Remove the deadcode disabler.
 #[allow(dead_code)]
 pub mod state;
+pub use build_result::*;
 pub use builder::*;
 pub use client::*;
 pub mod models;
@@ -1,10 +1,13 @@
+use serde::{Deserialize, Serialize};
+
 /// Represents the API level for the application, which determines the URL to be
 /// used.
 ///
 /// ## Documentations
 /// - <https://developer.visa.com/pages/going-live>
 /// - <https://developer.visa.com/pages/visa-developer-pki>
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ApiLevel {
     /// Use the sandbox base URL for testing and development.
     #[default]
@@ -14,3 +17,31 @@ pub enum ApiLevel {
     /// Use the production base URL for live operations.
     Production,
 }
+
+/// Whether the APIs served under `/vdp` (e.g. HelloWorld) use the prefix.
+///
+/// Visa serves these APIs under `/vdp` in sandbox and certification, and
+/// without the prefix in production. A proxy or a local stand-in may not follow
+/// the same rule.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VdpPrefix {
+    /// Use the prefix, except in [`ApiLevel::Production`].
+    #[default]
+    Auto,
+    /// Always use the prefix.
+    Always,
+    /// Never use the prefix.
+    Never,
+}
+
+impl VdpPrefix {
+    /// Whether the prefix applies at `api_level`.
+    pub fn applies(&self, api_level: ApiLevel) -> bool {
+        match self {
+            VdpPrefix::Auto => api_level != ApiLevel::Production,
+            VdpPrefix::Always => true,
+            VdpPrefix::Never => false,
+        }
+    }
+}
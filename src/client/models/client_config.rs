@@ -0,0 +1,454 @@
+use super::{
+    ApiLevel, MessageLevelEncryption, MessageLevelEncryptionBuilder, MutualTls, MutualTlsBuilder,
+    RetryPolicy, RetryPolicyBuilder, RootCertificates, VdpPrefix, XPayToken, XPayTokenBuilder,
+};
+use crate::{
+    client::{ClientBuildError, ClientBuildResult},
+    utils::Secret,
+};
+use serde::Deserialize;
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use url::Url;
+
+/// Client configuration, deserializable from TOML or JSON, or read from
+/// environment variables, so that services can share one configuration format.
+/// See [`VisaClientBuilder::from_config`][crate::client::VisaClientBuilder::from_config].
+///
+/// Relative paths are resolved against the directory of the configuration
+/// file, or the working directory otherwise. Secrets are redacted from the
+/// [`Debug`] output.
+///
+/// ## Example
+/// ```toml
+/// api_level = "certification"
+/// base_url = "https://egress.internal/visa"
+///
+/// [mutual_tls]
+/// user_id = "application-id-from-visa"
+/// password = "super-secret-password-from-visa"
+/// pkcs12_path = "client.p12"
+/// cert_key = "super-secret-key"
+///
+/// [message_level_encryption]
+/// key_id = "key-id-from-visa"
+/// client_private_key_path = "mle/client_key.pem"
+/// server_public_key_path = "mle/server_cert.pem"
+///
+/// [tls]
+/// root_certificates = ["certs/"]
+///
+/// [timeouts]
+/// connect_ms = 5000
+/// total_ms = 30000
+///
+/// [retry]
+/// max_retries = 3
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VisaClientConfig {
+    /// The API level, `sandbox` by default.
+    #[serde(default)]
+    pub api_level: ApiLevel,
+
+    /// Overrides the base URL of the API level.
+    pub base_url: Option<Url>,
+
+    /// Whether the APIs served under `/vdp` use the prefix, `auto` by
+    /// default.
+    #[serde(default)]
+    pub vdp_prefix: VdpPrefix,
+
+    /// Mutual TLS credentials.
+    pub mutual_tls: Option<MutualTlsConfig>,
+
+    /// X-Pay-Token credentials, for the APIs supporting it instead of mutual
+    /// TLS.
+    pub x_pay_token: Option<XPayTokenConfig>,
+
+    /// Message Level Encryption keys.
+    pub message_level_encryption: Option<MessageLevelEncryptionConfig>,
+
+    /// Trust settings.
+    #[serde(default)]
+    pub tls: TlsConfig,
+
+    /// Timeouts, none by default.
+    #[serde(default)]
+    pub timeouts: TimeoutsConfig,
+
+    /// Retry policy. Retries are disabled without it.
+    pub retry: Option<RetryConfig>,
+
+    /// Directory the relative paths are resolved against.
+    #[serde(skip)]
+    base_dir: Option<PathBuf>,
+}
+
+/// Mutual TLS credentials of a [`VisaClientConfig`], see [`MutualTls`].
+/// Either `pkcs12_path`, or both `cert_pem_path` and `key_pem_path`, must be
+/// set.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MutualTlsConfig {
+    pub user_id: String,
+    pub password: Secret<String>,
+    pub pkcs12_path: Option<PathBuf>,
+    pub cert_pem_path: Option<PathBuf>,
+    pub key_pem_path: Option<PathBuf>,
+    /// Passphrase of the PKCS#12 archive, or of the encrypted PEM private key.
+    pub cert_key: Option<Secret<String>>,
+}
+
+/// X-Pay-Token credentials of a [`VisaClientConfig`], see [`XPayToken`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct XPayTokenConfig {
+    pub api_key: String,
+    pub shared_secret: Secret<String>,
+}
+
+/// Message Level Encryption keys of a [`VisaClientConfig`], see
+/// [`MessageLevelEncryption`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MessageLevelEncryptionConfig {
+    pub key_id: String,
+    pub client_private_key_path: PathBuf,
+    pub client_private_key_pass: Option<Secret<String>>,
+    pub server_public_key_path: PathBuf,
+}
+
+/// Trust settings of a [`VisaClientConfig`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TlsConfig {
+    /// Root certificate files (PEM or DER) or directories to trust.
+    pub root_certificates: Vec<PathBuf>,
+    /// Whether the system root certificates are trusted, `true` by default.
+    pub system_roots: bool,
+    /// Whether the Visa roots are pinned, `false` by default.
+    pub visa_root_pinning: bool,
+}
+
+impl Default for TlsConfig {
+    fn default() -> Self {
+        TlsConfig {
+            root_certificates: Vec::new(),
+            system_roots: true,
+            visa_root_pinning: false,
+        }
+    }
+}
+
+/// Timeouts of a [`VisaClientConfig`], in milliseconds.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TimeoutsConfig {
+    pub connect_ms: Option<u64>,
+    pub read_ms: Option<u64>,
+    pub total_ms: Option<u64>,
+}
+
+/// Retry policy of a [`VisaClientConfig`], see [`RetryPolicy`]. Unset values
+/// take the defaults of the policy.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetryConfig {
+    pub max_retries: Option<u32>,
+    pub initial_backoff_ms: Option<u64>,
+    pub max_backoff_ms: Option<u64>,
+}
+
+impl VisaClientConfig {
+    /// Parses a TOML configuration.
+    pub fn from_toml(toml: &str) -> ClientBuildResult<Self> {
+        toml::from_str(toml).map_err(invalid_config)
+    }
+
+    /// Parses a JSON configuration.
+    pub fn from_json(json: &str) -> ClientBuildResult<Self> {
+        serde_json::from_str(json).map_err(invalid_config)
+    }
+
+    /// Reads a configuration file, parsed as JSON if its extension is `json`,
+    /// and as TOML otherwise. Relative paths of the configuration are
+    /// resolved against the directory of the file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> ClientBuildResult<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        let mut config = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json(&content)?,
+            _ => Self::from_toml(&content)?,
+        };
+        config.base_dir = path.parent().map(Path::to_path_buf);
+        Ok(config)
+    }
+
+    /// Reads the configuration from environment variables:
+    ///
+    /// | Variable | |
+    /// |---|---|
+    /// | `VISA_API_LEVEL` | `sandbox`, `certification` or `production` |
+    /// | `VISA_BASE_URL` | base URL override |
+    /// | `VISA_USER_ID`, `VISA_PASSWORD` | mutual TLS credentials |
+    /// | `VISA_CERT` | path of the PKCS#12 archive |
+    /// | `VISA_CERT_PEM`, `VISA_KEY_PEM` | paths of the PEM certificate chain and private key |
+    /// | `VISA_CERT_PASSWORD` | passphrase of the archive or private key |
+    /// | `VISA_MLE_KEY_ID` | MLE Key ID |
+    /// | `VISA_MLE_CLIENT_PRIVATE_KEY` | path of the MLE client private key |
+    /// | `VISA_MLE_CLIENT_PRIVATE_KEY_PASSWORD` | its passphrase |
+    /// | `VISA_MLE_SERVER_CERT` | path of the MLE server certificate |
+    /// | `VISA_CONNECT_TIMEOUT_MS`, `VISA_READ_TIMEOUT_MS`, `VISA_TIMEOUT_MS` | timeouts |
+    ///
+    /// The mutual TLS and MLE sections are only set if `VISA_USER_ID` and
+    /// `VISA_MLE_KEY_ID`, respectively, are.
+    pub fn from_env() -> ClientBuildResult<Self> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> ClientBuildResult<Self> {
+        let required = |name: &str| {
+            var(name).ok_or_else(|| ClientBuildError::InvalidConfig(format!("{name} is not set")))
+        };
+        let millis = |name: &str| {
+            var(name)
+                .map(|value| {
+                    value
+                        .parse::<u64>()
+                        .map_err(|e| ClientBuildError::InvalidConfig(format!("{name}: {e}")))
+                })
+                .transpose()
+        };
+
+        let api_level = match var("VISA_API_LEVEL") {
+            Some(api_level) => {
+                serde_json::from_value(serde_json::Value::String(api_level.to_lowercase()))
+                    .map_err(|e| ClientBuildError::InvalidConfig(format!("VISA_API_LEVEL: {e}")))?
+            }
+            None => ApiLevel::default(),
+        };
+        let base_url = var("VISA_BASE_URL")
+            .map(|base_url| Url::parse(&base_url))
+            .transpose()
+            .map_err(|e| ClientBuildError::InvalidConfig(format!("VISA_BASE_URL: {e}")))?;
+
+        let mutual_tls = match var("VISA_USER_ID") {
+            Some(user_id) => Some(MutualTlsConfig {
+                user_id,
+                password: required("VISA_PASSWORD")?.into(),
+                pkcs12_path: var("VISA_CERT").map(PathBuf::from),
+                cert_pem_path: var("VISA_CERT_PEM").map(PathBuf::from),
+                key_pem_path: var("VISA_KEY_PEM").map(PathBuf::from),
+                cert_key: var("VISA_CERT_PASSWORD").map(Secret::from),
+            }),
+            None => None,
+        };
+        let message_level_encryption = match var("VISA_MLE_KEY_ID") {
+            Some(key_id) => Some(MessageLevelEncryptionConfig {
+                key_id,
+                client_private_key_path: required("VISA_MLE_CLIENT_PRIVATE_KEY")?.into(),
+                client_private_key_pass: var("VISA_MLE_CLIENT_PRIVATE_KEY_PASSWORD")
+                    .map(Secret::from),
+                server_public_key_path: required("VISA_MLE_SERVER_CERT")?.into(),
+            }),
+            None => None,
+        };
+
+        Ok(VisaClientConfig {
+            api_level,
+            base_url,
+            mutual_tls,
+            message_level_encryption,
+            timeouts: TimeoutsConfig {
+                connect_ms: millis("VISA_CONNECT_TIMEOUT_MS")?,
+                read_ms: millis("VISA_READ_TIMEOUT_MS")?,
+                total_ms: millis("VISA_TIMEOUT_MS")?,
+            },
+            ..Default::default()
+        })
+    }
+
+    /// Loads the mutual TLS credentials, reading the certificate files.
+    pub fn mutual_tls(&self) -> ClientBuildResult<MutualTls> {
+        let config = self
+            .mutual_tls
+            .as_ref()
+            .ok_or_else(|| ClientBuildError::InvalidConfig("mutual_tls is not set".to_string()))?;
+
+        let mut builder = MutualTlsBuilder::default();
+        builder
+            .user_id(config.user_id.clone())
+            .password(config.password.clone())
+            .cert_key(
+                config
+                    .cert_key
+                    .as_ref()
+                    .map(|cert_key| cert_key.expose_secret().clone()),
+            );
+        if let Some(pkcs12_path) = &config.pkcs12_path {
+            builder.cert(std::fs::read(self.resolve(pkcs12_path))?);
+        }
+        if let Some(cert_pem_path) = &config.cert_pem_path {
+            builder.cert_pem(std::fs::read_to_string(self.resolve(cert_pem_path))?);
+        }
+        if let Some(key_pem_path) = &config.key_pem_path {
+            builder.key_pem(std::fs::read_to_string(self.resolve(key_pem_path))?);
+        }
+        builder.build().map_err(invalid_config)
+    }
+
+    /// Loads the X-Pay-Token credentials.
+    pub fn x_pay_token(&self) -> ClientBuildResult<XPayToken> {
+        let config = self
+            .x_pay_token
+            .as_ref()
+            .ok_or_else(|| ClientBuildError::InvalidConfig("x_pay_token is not set".to_string()))?;
+
+        XPayTokenBuilder::default()
+            .api_key(config.api_key.clone())
+            .shared_secret(config.shared_secret.clone())
+            .build()
+            .map_err(invalid_config)
+    }
+
+    /// Loads the Message Level Encryption keys, reading the key files, if
+    /// configured.
+    pub fn message_level_encryption(&self) -> ClientBuildResult<Option<MessageLevelEncryption>> {
+        let Some(config) = &self.message_level_encryption else {
+            return Ok(None);
+        };
+
+        MessageLevelEncryptionBuilder::default()
+            .key_id(config.key_id.clone())
+            .client_private_key(std::fs::read_to_string(
+                self.resolve(&config.client_private_key_path),
+            )?)
+            .client_private_key_pass(
+                config
+                    .client_private_key_pass
+                    .as_ref()
+                    .map(|pass| pass.expose_secret().clone()),
+            )
+            .server_public_key(std::fs::read_to_string(
+                self.resolve(&config.server_public_key_path),
+            )?)
+            .build()
+            .map(Some)
+            .map_err(invalid_config)
+    }
+
+    /// The configured root certificates.
+    pub(crate) fn root_certificates(&self) -> Vec<RootCertificates> {
+        self.tls
+            .root_certificates
+            .iter()
+            .map(|path| {
+                let path = self.resolve(path);
+                match path.is_dir() {
+                    true => RootCertificates::Directory(path),
+                    false => RootCertificates::File(path),
+                }
+            })
+            .collect()
+    }
+
+    /// The configured retry policy, if any.
+    pub(crate) fn retry_policy(&self) -> ClientBuildResult<Option<RetryPolicy>> {
+        let Some(config) = &self.retry else {
+            return Ok(None);
+        };
+
+        let mut builder = RetryPolicyBuilder::default();
+        if let Some(max_retries) = config.max_retries {
+            builder.max_retries(max_retries);
+        }
+        if let Some(initial_backoff_ms) = config.initial_backoff_ms {
+            builder.initial_backoff(Duration::from_millis(initial_backoff_ms));
+        }
+        if let Some(max_backoff_ms) = config.max_backoff_ms {
+            builder.max_backoff(Duration::from_millis(max_backoff_ms));
+        }
+        builder.build().map(Some).map_err(invalid_config)
+    }
+
+    fn resolve(&self, path: &Path) -> PathBuf {
+        match &self.base_dir {
+            Some(base_dir) if path.is_relative() => base_dir.join(path),
+            _ => path.to_path_buf(),
+        }
+    }
+}
+
+fn invalid_config(error: impl std::fmt::Display) -> ClientBuildError {
+    ClientBuildError::InvalidConfig(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_from_json() {
+        let config = VisaClientConfig::from_json(
+            r#"{
+                "api_level": "production",
+                "x_pay_token": { "api_key": "api-key", "shared_secret": "shared-secret" },
+                "timeouts": { "read_ms": 10000 }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.api_level, ApiLevel::Production);
+        assert_eq!(config.timeouts.read_ms, Some(10000));
+        assert!(config.tls.system_roots);
+        assert_eq!(config.x_pay_token().unwrap().api_key, "api-key");
+        assert!(config.message_level_encryption().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_from_toml_unknown_field() {
+        assert!(matches!(
+            VisaClientConfig::from_toml(r#"api_levle = "production""#),
+            Err(ClientBuildError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_vars() {
+        let vars = HashMap::from([
+            ("VISA_API_LEVEL", "Certification"),
+            ("VISA_USER_ID", "user"),
+            ("VISA_PASSWORD", "password"),
+            ("VISA_CERT", "client.p12"),
+            ("VISA_CERT_PASSWORD", "mtls-secret"),
+            ("VISA_TIMEOUT_MS", "30000"),
+        ]);
+        let config =
+            VisaClientConfig::from_vars(|name| vars.get(name).map(|value| value.to_string()))
+                .unwrap();
+
+        assert_eq!(config.api_level, ApiLevel::Certification);
+        assert_eq!(config.timeouts.total_ms, Some(30000));
+        let mutual_tls = config.mutual_tls.as_ref().unwrap();
+        assert_eq!(mutual_tls.user_id, "user");
+        assert_eq!(mutual_tls.pkcs12_path, Some(PathBuf::from("client.p12")));
+        assert!(config.message_level_encryption.is_none());
+
+        let debug = format!("{config:?}");
+        assert!(!debug.contains(r#""password""#) && !debug.contains("mtls-secret"));
+    }
+
+    #[test]
+    fn test_from_vars_missing_password() {
+        let config = VisaClientConfig::from_vars(|name| {
+            (name == "VISA_USER_ID").then(|| "user".to_string())
+        });
+
+        assert!(matches!(config, Err(ClientBuildError::InvalidConfig(_))));
+    }
+}
@@ -1,6 +1,80 @@
-use super::api_level::ApiLevel;
+use super::api_level::{ApiLevel, VdpPrefix};
+use crate::api::constants;
+use url::Url;
 
 #[derive(Default, Clone, Debug)]
 pub struct Config {
-  pub api_level: ApiLevel
+    pub api_level: ApiLevel,
+
+    /// Overrides the base URL of the [`ApiLevel`], e.g. to route through a
+    /// proxy, or to a local stand-in.
+    pub base_url: Option<Url>,
+
+    /// Whether the APIs served under `/vdp` outside of production use the
+    /// prefix.
+    pub vdp_prefix: VdpPrefix,
+}
+
+impl Config {
+    /// The base URL the API paths are joined to. Always ends with a `/`, so
+    /// that a path of the override (e.g. `https://proxy.internal/visa`) is
+    /// kept.
+    pub fn base_url(&self) -> Url {
+        let mut base_url = match &self.base_url {
+            Some(base_url) => base_url.clone(),
+            None => match self.api_level {
+                ApiLevel::Sandbox => constants::VISA_DOMAIN_SANDBOX.clone(),
+                ApiLevel::Certification => constants::VISA_DOMAIN_CERTIFICATION.clone(),
+                ApiLevel::Production => constants::VISA_DOMAIN_PRODUCTION.clone(),
+            },
+        };
+        if !base_url.path().ends_with('/') {
+            let path = format!("{}/", base_url.path());
+            base_url.set_path(&path);
+        }
+        base_url
+    }
+
+    /// Whether the `/vdp` prefix applies, see [`VdpPrefix`].
+    pub fn uses_vdp_prefix(&self) -> bool {
+        self.vdp_prefix.applies(self.api_level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_url() {
+        let config = Config {
+            api_level: ApiLevel::Certification,
+            ..Default::default()
+        };
+        assert_eq!(config.base_url().as_str(), "https://cert.api.visa.com/");
+
+        let config = Config {
+            base_url: Some(Url::parse("https://proxy.internal/visa").unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(config.base_url().as_str(), "https://proxy.internal/visa/");
+        assert_eq!(
+            config.base_url().join("vdp/helloworld").unwrap().as_str(),
+            "https://proxy.internal/visa/vdp/helloworld"
+        );
+    }
+
+    #[test]
+    fn test_uses_vdp_prefix() {
+        let config = |api_level, vdp_prefix| Config {
+            api_level,
+            vdp_prefix,
+            ..Default::default()
+        };
+
+        assert!(config(ApiLevel::Sandbox, VdpPrefix::Auto).uses_vdp_prefix());
+        assert!(!config(ApiLevel::Production, VdpPrefix::Auto).uses_vdp_prefix());
+        assert!(config(ApiLevel::Production, VdpPrefix::Always).uses_vdp_prefix());
+        assert!(!config(ApiLevel::Sandbox, VdpPrefix::Never).uses_vdp_prefix());
+    }
 }
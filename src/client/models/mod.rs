@@ -1,10 +1,20 @@
 mod api_level;
+mod client_config;
 mod config;
 mod message_level_encryption;
 mod mutual_tls;
+mod request_options;
+mod retry_policy;
+mod root_certificates;
+mod x_pay_token;
 
 pub use api_level::*;
+pub use client_config::*;
 #[doc(hidden)]
 pub use config::*;
 pub use message_level_encryption::*;
 pub use mutual_tls::*;
+pub use request_options::*;
+pub use retry_policy::*;
+pub use root_certificates::*;
+pub use x_pay_token::*;
@@ -1,38 +1,59 @@
+use crate::utils::{BuilderError, BuilderResult, Secret};
 use derive_builder::Builder;
 
 /// Mutual TLS is required by all APIs, as mentioned in the Visa API
 /// Documentation.
 ///
-/// Important notes: you will need to provide your own CA Bundle for this.
+/// Important notes: you will need to provide your own CA Bundle for this, see
+/// [`VisaClientBuilder::add_root_certificates`][crate::client::VisaClientBuilder::add_root_certificates]
+/// and
+/// [`VisaClientBuilder::set_visa_root_pinning`][crate::client::VisaClientBuilder::set_visa_root_pinning].
 /// Please check Visa's documentation for more information. You may also use the
-/// systemn CA bundle.
+/// system CA bundle.
 ///
 /// Please see the following links for more information:
 /// - <https://developer.visa.com/pages/working-with-visa-apis/visa-developer-quick-start-guide#section5>
 ///
-/// This crate will load the certificate using reqwest's Identity struct in
-/// PKCS12 format. Please consult with
-/// [reqwest's documentation][reqwest::Identity::from_pkcs12_der] for more information.
+/// The client certificate can be provided either as a PKCS#12 archive (see
+/// [`MutualTlsBuilder::cert`]), or as a PEM certificate chain with its private
+/// key (see [`MutualTlsBuilder::pem`]), as handed out by Visa's developer
+/// portal. PKCS#8 and PKCS#1 private keys are supported, and encrypted PKCS#8
+/// keys are decrypted with [`MutualTlsBuilder::cert_key`].
 ///
 /// ## Example
 /// ```no_run
 /// use visa_sdk::client::{VisaClientBuilder, models::MutualTlsBuilder};
-/// use visa_sdk::api::hello_world;
 ///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// // From a PKCS#12 archive.
 /// let mtls = MutualTlsBuilder::default()
 ///     .user_id("application-id-from-visa")
 ///     .password("super-secret-password-from-visa")
-///     .cert("--- BEGIN ...".as_bytes().to_vec())
+///     .cert(std::fs::read("client.p12")?)
 ///     .cert_key(Some(String::from("super-secret-key")))
-///     .build()
-///     .unwrap();
+///     .build()?;
+///
+/// // From the PEM certificate and private key.
+/// let mtls = MutualTlsBuilder::default()
+///     .user_id("application-id-from-visa")
+///     .password("super-secret-password-from-visa")
+///     .pem(
+///         std::fs::read_to_string("cert.pem")?,
+///         std::fs::read_to_string("key.pem")?,
+///     )
+///     .build()?;
 ///
 /// let client = VisaClientBuilder::new()
 ///     .set_mutual_tls(mtls)
-///     .build();
+///     .build()?;
+/// # Ok(())
+/// # }
 /// ```
-#[derive(Default, Clone, Debug, Builder)]
-#[builder(build_fn(error = "crate::utils::BuilderError"))]
+///
+/// The password, the certificate and the private key are zeroized on drop, and
+/// redacted from the [`Debug`] output.
+#[derive(Default, Clone, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
 pub struct MutualTls {
     /// The user ID to use for the API Client to authenticate. This value is
     /// obtainable in your application dashboard.
@@ -42,17 +63,92 @@ pub struct MutualTls {
     /// The password to use for the API Client to authenticate. This value is
     /// also obtainable in your application dashboard.
     #[builder(setter(into))]
-    pub(crate) password: String,
+    pub(crate) password: Secret<String>,
 
-    /// The certificate to use for the client, the certificate content, not the
-    /// path. Certificate should be in PKCS12 format. This certificate will be
-    /// loaded by reqwest's Identity struct.
+    /// The client certificate as a PKCS#12 archive, the archive content, not
+    /// the path. Optional when the certificate is given in PEM format with
+    /// [`pem`][MutualTlsBuilder::pem], and ignored in that case.
     ///
-    /// See [`reqwest::Identity::from_pkcs12_der`] for more information.
-    pub(crate) cert: Vec<u8>,
+    /// The archive is opened with the [passphrase][MutualTlsBuilder::cert_key]
+    /// when the client is built, checked for a private key and a currently
+    /// valid certificate, then handed to the TLS backend as a PEM certificate
+    /// chain and a PKCS#8 private key.
+    #[builder(setter(into), default)]
+    pub(crate) cert: Secret<Vec<u8>>,
+
+    /// The client certificate chain in PEM format, leaf certificate first.
+    /// Used instead of [`cert`][MutualTlsBuilder::cert], together with
+    /// [`key_pem`][MutualTlsBuilder::key_pem].
+    #[builder(setter(into, strip_option), default)]
+    pub(crate) cert_pem: Option<String>,
 
-    /// Certificate Passphrase if any. If the certificate is not password
+    /// The private key of the PEM certificate, in PKCS#8 (optionally
+    /// encrypted) or PKCS#1 PEM format.
+    #[builder(setter(into, strip_option), default)]
+    pub(crate) key_pem: Option<Secret<String>>,
+
+    /// Certificate Passphrase if any: the passphrase of the PKCS#12 archive,
+    /// or of the encrypted PEM private key. If the certificate is not password
     /// protected, this should be [None].
-    #[builder(setter(into))]
-    pub(crate) cert_key: Option<String>,
+    #[builder(setter(custom), default)]
+    pub(crate) cert_key: Option<Secret<String>>,
+}
+
+impl std::fmt::Debug for MutualTls {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_struct("MutualTls")
+            .field("user_id", &self.user_id)
+            .field("password", &self.password)
+            .field("cert", &self.cert)
+            .field("cert_pem", &self.cert_pem)
+            .field("key_pem", &self.key_pem)
+            .field("cert_key", &self.cert_key)
+            .finish()
+    }
+}
+
+impl MutualTlsBuilder {
+    /// Certificate Passphrase if any: the passphrase of the PKCS#12 archive,
+    /// or of the encrypted PEM private key. If the certificate is not password
+    /// protected, this should be [None].
+    pub fn cert_key<V: Into<String>>(&mut self, cert_key: Option<V>) -> &mut Self {
+        self.cert_key = Some(cert_key.map(|cert_key| Secret::new(cert_key.into())));
+        self
+    }
+
+    /// Uses a PEM certificate chain and its private key, instead of a PKCS#12
+    /// archive. Shorthand for [`cert_pem`][Self::cert_pem] and
+    /// [`key_pem`][Self::key_pem].
+    pub fn pem<C: Into<String>, K: Into<String>>(
+        &mut self,
+        cert_chain: C,
+        private_key: K,
+    ) -> &mut Self {
+        self.cert_pem(cert_chain).key_pem(private_key.into())
+    }
+
+    fn validate(&self) -> BuilderResult<()> {
+        let has_pkcs12 = self
+            .cert
+            .as_ref()
+            .is_some_and(|cert| !cert.expose_secret().is_empty());
+        let cert_pem = self.cert_pem.clone().flatten();
+        let key_pem = self.key_pem.clone().flatten();
+
+        match (has_pkcs12, cert_pem.is_some(), key_pem.is_some()) {
+            (true, false, false) | (false, true, true) => Ok(()),
+            (true, _, _) => Err(BuilderError::ValidationViolition(
+                "cert cannot be combined with cert_pem or key_pem".to_string(),
+            )),
+            (false, true, false) => Err(BuilderError::ValidationViolition(
+                "cert_pem requires key_pem".to_string(),
+            )),
+            (false, false, true) => Err(BuilderError::ValidationViolition(
+                "key_pem requires cert_pem".to_string(),
+            )),
+            (false, false, false) => Err(BuilderError::ValidationViolition(
+                "either cert, or cert_pem and key_pem must be set".to_string(),
+            )),
+        }
+    }
 }
@@ -0,0 +1,54 @@
+use derive_builder::Builder;
+use reqwest::Method;
+use std::time::Duration;
+
+/// Per-call options, see
+/// [`VisaClient::execute_request_with_options`][crate::client::VisaClient::execute_request_with_options].
+///
+/// Never mark a Visa Direct push idempotent: a retried push that reached Visa
+/// is a second payout. Reconcile a push whose outcome is unknown with
+/// [`TransactionQuery::reconcile_push`][crate::api::visa_direct::query::TransactionQuery::reconcile_push]
+/// instead.
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+/// use visa_sdk::client::models::RequestOptionsBuilder;
+///
+/// // A foreign exchange rate lookup, a `POST` without side effects, which is
+/// // safe to retry.
+/// let options = RequestOptionsBuilder::default()
+///     .timeout(Duration::from_secs(30))
+///     .idempotent(true)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Default, Clone, Debug, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError"))]
+pub struct RequestOptions {
+    /// Total timeout of each attempt of the call, overriding the timeout of
+    /// the client.
+    #[builder(setter(strip_option), default)]
+    pub(crate) timeout: Option<Duration>,
+
+    /// Whether the call is safe to retry. Defaults to the idempotency of the
+    /// HTTP method: `GET`, `HEAD`, `OPTIONS`, `PUT` and `DELETE` are, `POST`
+    /// and `PATCH` are not. Set it for calls carrying an idempotency key.
+    #[builder(setter(strip_option), default)]
+    pub(crate) idempotent: Option<bool>,
+
+    /// The `x-correlation-id` sent with the call. A random one is generated
+    /// if neither this, nor the header of the request, is set.
+    #[builder(setter(into, strip_option), default)]
+    pub(crate) correlation_id: Option<String>,
+}
+
+impl RequestOptions {
+    /// Whether a call with `method` is safe to retry.
+    pub(crate) fn is_idempotent(&self, method: &Method) -> bool {
+        self.idempotent.unwrap_or(matches!(
+            *method,
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+        ))
+    }
+}
@@ -0,0 +1,63 @@
+use crate::utils::{BuilderError, BuilderResult};
+use derive_builder::Builder;
+use std::time::Duration;
+
+/// Retry policy of the client, see
+/// [`VisaClientBuilder::set_retry_policy`][crate::client::VisaClientBuilder::set_retry_policy].
+/// Retries are disabled unless a policy is set.
+///
+/// Connection errors, `429 Too Many Requests` and `5xx` responses are retried
+/// with an exponential backoff and jitter, or after the delay of the
+/// `Retry-After` header when Visa sends one. Only idempotent calls are
+/// retried, see [`RequestOptions`][super::RequestOptions].
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+/// use visa_sdk::client::models::RetryPolicyBuilder;
+///
+/// let retry_policy = RetryPolicyBuilder::default()
+///     .max_retries(5u32)
+///     .initial_backoff(Duration::from_millis(100))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+pub struct RetryPolicy {
+    /// Maximum number of retries, after the first attempt. Defaults to 3.
+    #[builder(default = "3")]
+    pub(crate) max_retries: u32,
+
+    /// Backoff before the first retry, doubled on each subsequent retry.
+    /// Defaults to 200ms.
+    #[builder(default = "Duration::from_millis(200)")]
+    pub(crate) initial_backoff: Duration,
+
+    /// Upper bound of the backoff. A `Retry-After` longer than this is not
+    /// waited for, and the response is returned as is. Defaults to 10s.
+    #[builder(default = "Duration::from_secs(10)")]
+    pub(crate) max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicyBuilder::default()
+            .build()
+            .expect("the default retry policy is valid")
+    }
+}
+
+impl RetryPolicyBuilder {
+    fn validate(&self) -> BuilderResult<()> {
+        if let (Some(initial_backoff), Some(max_backoff)) = (self.initial_backoff, self.max_backoff)
+        {
+            if initial_backoff > max_backoff {
+                return Err(BuilderError::ValidationViolition(
+                    "initial_backoff cannot exceed max_backoff".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
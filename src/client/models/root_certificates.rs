@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+
+/// Extra root certificates trusted by the client, in addition to (or, with
+/// [`set_system_roots(false)`][crate::client::VisaClientBuilder::set_system_roots],
+/// instead of) the system roots.
+///
+/// Files are read when the client is built. Whether a file holds PEM or DER
+/// is detected from its content; PEM files may hold several certificates.
+///
+/// ## Example
+/// ```no_run
+/// use visa_sdk::client::{models::RootCertificates, VisaClientBuilder};
+///
+/// let builder = VisaClientBuilder::new()
+///     .add_root_certificates(RootCertificates::File("visa_ca_bundle.pem".into()))
+///     .add_root_certificates(RootCertificates::Directory("/etc/visa/certs".into()))
+///     .set_system_roots(false);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum RootCertificates {
+    /// One or more certificates in PEM format.
+    Pem(Vec<u8>),
+
+    /// A single certificate in DER format.
+    Der(Vec<u8>),
+
+    /// A PEM or DER file.
+    File(PathBuf),
+
+    /// A directory of PEM or DER files. Only files with a `.pem`, `.crt`,
+    /// `.cer` or `.der` extension are loaded; subdirectories are skipped.
+    Directory(PathBuf),
+}
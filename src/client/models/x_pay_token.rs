@@ -0,0 +1,63 @@
+use crate::utils::{BuilderError, BuilderResult, Secret};
+use derive_builder::Builder;
+
+/// X-Pay-Token credentials: an API key and its shared secret.
+///
+/// Some Visa products (e.g. Merchant Locator, Offers, ATM Locator) authenticate
+/// over one-way TLS with an API key, sent as the `apikey` query parameter, and
+/// an `x-pay-token` header: an HMAC-SHA256 of the request, keyed with the
+/// shared secret. Both values are obtainable in your application dashboard.
+///
+/// ## Example
+/// ```no_run
+/// use visa_sdk::api::hello_world::HelloWorld;
+/// use visa_sdk::client::{models::XPayTokenBuilder, VisaClientBuilder};
+///
+/// let x_pay_token = XPayTokenBuilder::default()
+///     .api_key("api-key-from-visa")
+///     .shared_secret("shared-secret-from-visa")
+///     .build()
+///     .unwrap();
+///
+/// let client = VisaClientBuilder::new()
+///     .set_x_pay_token(x_pay_token)
+///     .build()
+///     .expect("Failed to build VisaClient");
+///
+/// // HelloWorld accepts X-Pay-Token clients.
+/// let hello_world = HelloWorld::new(client);
+/// ```
+///
+/// ## Documentation
+/// - <https://developer.visa.com/pages/working-with-visa-apis/x-pay-token>
+#[derive(Default, Clone, Debug, Builder)]
+#[builder(build_fn(error = "crate::utils::BuilderError", validate = "Self::validate"))]
+pub struct XPayToken {
+    /// The API key, sent as the `apikey` query parameter.
+    #[builder(setter(into))]
+    pub(crate) api_key: String,
+
+    /// The shared secret, used to sign the requests.
+    #[builder(setter(into))]
+    pub(crate) shared_secret: Secret<String>,
+}
+
+impl XPayTokenBuilder {
+    fn validate(&self) -> BuilderResult<()> {
+        if self.api_key.as_ref().is_some_and(String::is_empty) {
+            return Err(BuilderError::ValidationViolition(
+                "api_key cannot be empty".to_string(),
+            ));
+        }
+        if self
+            .shared_secret
+            .as_ref()
+            .is_some_and(|secret| secret.expose_secret().is_empty())
+        {
+            return Err(BuilderError::ValidationViolition(
+                "shared_secret cannot be empty".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+//! # Retries
+//!
+//! Decides whether, and after which delay, an attempt is retried under a
+//! [`RetryPolicy`].
+
+use super::models::RetryPolicy;
+use crate::api::result::{Error, Result};
+use rand::Rng;
+use reqwest::{header, StatusCode};
+use std::time::{Duration, SystemTime};
+
+/// The delay before retrying the `attempt`th retry (starting at 0), or
+/// [None] if the `result` of the previous attempt must be returned.
+pub(crate) fn delay(
+    policy: &RetryPolicy,
+    attempt: u32,
+    result: &Result<reqwest::Response>,
+) -> Option<Duration> {
+    if attempt >= policy.max_retries {
+        return None;
+    }
+
+    match result {
+        Ok(response) if is_retryable_status(response.status()) => {
+            match retry_after(response.headers()) {
+                Some(delay) if delay > policy.max_backoff => None,
+                Some(delay) => Some(delay),
+                None => Some(backoff(policy, attempt)),
+            }
+        }
+        Err(Error::RequestError(error)) if error.is_connect() => Some(backoff(policy, attempt)),
+        _ => None,
+    }
+}
+
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Exponential backoff with jitter: a random delay between half and all of
+/// `initial_backoff * 2^attempt`, capped to `max_backoff`.
+pub(crate) fn backoff(policy: &RetryPolicy, attempt: u32) -> Duration {
+    let backoff = policy
+        .initial_backoff
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(policy.max_backoff);
+    rand::thread_rng().gen_range(backoff / 2..=backoff)
+}
+
+/// The delay of the `Retry-After` header, either in seconds or as an HTTP
+/// date.
+pub(crate) fn retry_after(headers: &header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::models::RetryPolicyBuilder;
+    use http::response::Builder as ResponseBuilder;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicyBuilder::default()
+            .max_retries(2u32)
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_secs(1))
+            .build()
+            .unwrap()
+    }
+
+    fn response(status: u16, retry_after: Option<&str>) -> Result<reqwest::Response> {
+        let mut builder = ResponseBuilder::new().status(status);
+        if let Some(retry_after) = retry_after {
+            builder = builder.header(header::RETRY_AFTER, retry_after);
+        }
+        Ok(builder.body("").unwrap().into())
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = policy();
+        for _ in 0..100 {
+            let first = backoff(&policy, 0);
+            assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+
+            let third = backoff(&policy, 2);
+            assert!(third >= Duration::from_millis(200) && third <= Duration::from_millis(400));
+
+            // Capped to max_backoff.
+            assert!(backoff(&policy, 31) <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn test_delay_statuses() {
+        let policy = policy();
+        assert!(delay(&policy, 0, &response(503, None)).is_some());
+        assert!(delay(&policy, 0, &response(429, None)).is_some());
+        assert!(delay(&policy, 0, &response(400, None)).is_none());
+        assert!(delay(&policy, 0, &response(200, None)).is_none());
+        // Retries exhausted.
+        assert!(delay(&policy, 2, &response(503, None)).is_none());
+    }
+
+    #[test]
+    fn test_delay_retry_after() {
+        let policy = policy();
+        assert_eq!(
+            delay(&policy, 0, &response(429, Some("1"))),
+            Some(Duration::from_secs(1))
+        );
+        // Too long to wait for.
+        assert_eq!(delay(&policy, 0, &response(429, Some("120"))), None);
+
+        let date = httpdate::fmt_http_date(SystemTime::now() - Duration::from_secs(5));
+        assert_eq!(
+            delay(&policy, 0, &response(503, Some(&date))),
+            Some(Duration::ZERO)
+        );
+    }
+}
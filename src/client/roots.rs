@@ -0,0 +1,188 @@
+//! # Root Certificates
+//!
+//! Resolves the [`RootCertificates`] configured on the
+//! [`VisaClientBuilder`][super::VisaClientBuilder] into certificates for the
+//! TLS backend, and holds the Visa roots used for pinning.
+
+use super::{
+    build_result::{ClientBuildError, ClientBuildResult},
+    models::RootCertificates,
+};
+use std::path::Path;
+use x509_cert::der::{Decode, Encode};
+
+/// Root certificates of the Visa API endpoints, see
+/// <https://developer.visa.com/pages/working-with-visa-apis/two-way-ssl#section2>.
+const VISA_ROOTS: &[&[u8]] = &[
+    include_bytes!("certs/DigiCert_Global_Root_CA.pem"),
+    include_bytes!("certs/DigiCert_Global_Root_G2.pem"),
+    include_bytes!("certs/Entrust_Root_Certification_Authority_-_G2.pem"),
+];
+
+/// File extensions loaded from a [`RootCertificates::Directory`].
+const CERTIFICATE_EXTENSIONS: &[&str] = &["pem", "crt", "cer", "der"];
+
+/// Trust settings of the client.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TrustConfig {
+    pub(crate) root_certificates: Vec<RootCertificates>,
+    pub(crate) disable_system_roots: bool,
+    pub(crate) pin_visa_roots: bool,
+}
+
+impl TrustConfig {
+    /// Applies the trust settings to the reqwest client builder.
+    ///
+    /// Pinning disables the system roots, and trusts the Visa roots instead.
+    /// Explicitly added root certificates are trusted in any case.
+    pub(crate) fn apply(
+        &self,
+        mut builder: reqwest::ClientBuilder,
+    ) -> ClientBuildResult<reqwest::ClientBuilder> {
+        if self.disable_system_roots || self.pin_visa_roots {
+            builder = builder.tls_built_in_root_certs(false);
+        }
+        if self.pin_visa_roots {
+            for root in visa_roots()? {
+                builder = builder.add_root_certificate(root);
+            }
+        }
+        for roots in &self.root_certificates {
+            for root in load(roots)? {
+                builder = builder.add_root_certificate(root);
+            }
+        }
+        Ok(builder)
+    }
+}
+
+/// The embedded Visa root certificates.
+pub(crate) fn visa_roots() -> ClientBuildResult<Vec<reqwest::Certificate>> {
+    VISA_ROOTS
+        .iter()
+        .map(|pem| reqwest::Certificate::from_pem(pem).map_err(|e| invalid_root("Visa root", e)))
+        .collect()
+}
+
+/// Loads the certificates of `roots`.
+pub(crate) fn load(roots: &RootCertificates) -> ClientBuildResult<Vec<reqwest::Certificate>> {
+    match roots {
+        RootCertificates::Pem(pem) => from_pem(pem, "PEM root certificates"),
+        RootCertificates::Der(der) => Ok(vec![from_der(der, "DER root certificate")?]),
+        RootCertificates::File(path) => from_file(path),
+        RootCertificates::Directory(path) => {
+            let mut entries = std::fs::read_dir(path)?
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<_>, _>>()?;
+            entries.sort();
+
+            let mut certificates = Vec::new();
+            for path in entries.iter().filter(|path| is_certificate_file(path)) {
+                certificates.extend(from_file(path)?);
+            }
+            Ok(certificates)
+        }
+    }
+}
+
+fn from_file(path: &Path) -> ClientBuildResult<Vec<reqwest::Certificate>> {
+    let content = std::fs::read(path)?;
+    let source = path.display().to_string();
+    if content.trim_ascii_start().starts_with(b"-----BEGIN") {
+        from_pem(&content, &source)
+    } else {
+        Ok(vec![from_der(&content, &source)?])
+    }
+}
+
+/// Certificates are parsed here rather than by the TLS backend, as some
+/// backends only parse them once the client is built.
+fn from_pem(pem: &[u8], source: &str) -> ClientBuildResult<Vec<reqwest::Certificate>> {
+    let certificates =
+        x509_cert::Certificate::load_pem_chain(pem).map_err(|e| invalid_root(source, e))?;
+    if certificates.is_empty() {
+        return Err(ClientBuildError::InvalidRootCertificate(format!(
+            "{source}: no certificate found"
+        )));
+    }
+    certificates
+        .iter()
+        .map(|certificate| {
+            let der = certificate.to_der().map_err(|e| invalid_root(source, e))?;
+            reqwest::Certificate::from_der(&der).map_err(|e| invalid_root(source, e))
+        })
+        .collect()
+}
+
+fn from_der(der: &[u8], source: &str) -> ClientBuildResult<reqwest::Certificate> {
+    x509_cert::Certificate::from_der(der).map_err(|e| invalid_root(source, e))?;
+    reqwest::Certificate::from_der(der).map_err(|e| invalid_root(source, e))
+}
+
+fn is_certificate_file(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| {
+                CERTIFICATE_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str())
+            })
+}
+
+fn invalid_root(source: &str, error: impl std::fmt::Display) -> ClientBuildError {
+    ClientBuildError::InvalidRootCertificate(format!("{source}: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn testdata(path: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/client/testdata")
+            .join(path)
+    }
+
+    #[test]
+    fn test_visa_roots() {
+        assert_eq!(visa_roots().unwrap().len(), VISA_ROOTS.len());
+    }
+
+    #[test]
+    fn test_load_pem_and_der() {
+        let pem = std::fs::read(testdata("roots/mle_root.pem")).unwrap();
+        assert_eq!(load(&RootCertificates::Pem(pem)).unwrap().len(), 1);
+
+        let der = std::fs::read(testdata("roots/test_ca.der")).unwrap();
+        assert_eq!(load(&RootCertificates::Der(der)).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_load_file() {
+        let pem = load(&RootCertificates::File(testdata("roots/mle_root.pem"))).unwrap();
+        assert_eq!(pem.len(), 1);
+
+        let der = load(&RootCertificates::File(testdata("roots/test_ca.der"))).unwrap();
+        assert_eq!(der.len(), 1);
+    }
+
+    #[test]
+    fn test_load_directory() {
+        // README.txt is skipped.
+        let roots = load(&RootCertificates::Directory(testdata("roots"))).unwrap();
+        assert_eq!(roots.len(), 2);
+    }
+
+    #[test]
+    fn test_load_errors() {
+        assert!(matches!(
+            load(&RootCertificates::Pem(b"not a certificate".to_vec())),
+            Err(ClientBuildError::InvalidRootCertificate(_))
+        ));
+        assert!(matches!(
+            load(&RootCertificates::File(testdata("roots/missing.pem"))),
+            Err(ClientBuildError::Io(_))
+        ));
+    }
+}
@@ -10,7 +10,7 @@
 //! The module is structured around two main sets of states:
 //!
 //! - **Mutual TLS States**: Structures and traits that define whether Mutual
-//!   TLS is enabled or not.
+//!   TLS is enabled or not, or replaced by an X-Pay-Token.
 //! - **Message Level Encryption States**: Structures and traits that define
 //!   whether Message Level Encryption is enabled or not.
 //!
@@ -18,19 +18,33 @@
 //!
 //! #### Mutual TLS States
 //! - [`WithoutMutualTls`]: Represents the state where Mutual TLS is not
-//!       enabled. You cannot construct [`VisaClient`] with this state, you need
-//!       to provide the mutual tls.
+//!   enabled. You cannot construct [`VisaClient`] with this state, you need
+//!   to provide the mutual tls.
 //! - [`WithMutualTls`]: Represents the state where Mutual TLS is enabled.
+//!   The requests are authenticated with basic auth.
+//! - [`WithXPayToken`]: Represents the state where the requests are
+//!   authenticated with an X-Pay-Token, over one-way TLS. It takes the place
+//!   of [`WithMutualTls`], for the APIs that support it.
+//!
+//! The states a [`VisaClient`] can be built with implement
+//! [`AuthenticationState`]. APIs declare the authentication they require with
+//! the second type parameter of [`VisaClient`], which defaults to
+//! [`WithMutualTls`]. APIs accepting any authentication, like
+//! [`HelloWorld`][crate::api::hello_world::HelloWorld], are generic over it.
 //!
 //! #### Message Level Encryption States
 //! - [`WithoutMessageLevelEncryption`]: Represents the state where Message
-//!       Level Encryption is not enabled.
+//!   Level Encryption is not enabled.
 //! - [`WithMessageLevelEncryption`]: Represents the state where Message Level
-//!       Encryption is enabled.
+//!   Encryption is enabled.
 
-use super::models::{MessageLevelEncryption, MutualTls};
+use super::models::{MessageLevelEncryption, MutualTls, XPayToken};
 #[allow(unused_imports)] // for documentation purposes.
 use super::VisaClient;
+use crate::api::result::{Error, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use url::Url;
+use zeroize::Zeroizing;
 
 // region:    --- Mutual TLS ---
 /// Represents the state where Mutual TLS is not enabled.
@@ -49,6 +63,49 @@ impl MutualTlsState for WithoutMutualTls {}
 
 /// Implementation of `MutualTlsState` for `WithMutualTls`.
 impl MutualTlsState for WithMutualTls {}
+
+/// Represents the state where the requests are authenticated with an
+/// X-Pay-Token instead of Mutual TLS.
+#[derive(Default, Clone, Debug)]
+pub struct WithXPayToken(pub(crate) XPayToken);
+
+/// Implementation of `MutualTlsState` for `WithXPayToken`.
+impl MutualTlsState for WithXPayToken {}
+
+/// Trait representing how a built client authenticates its requests.
+pub trait AuthenticationState: Clone {
+    /// Applies the credentials to the request. Called right before the
+    /// request is sent, after Message Level Encryption. `base_url` is the
+    /// base URL of the client, which the request URL is joined to.
+    #[doc(hidden)]
+    fn authenticate(&self, request: &mut reqwest::Request, base_url: &Url) -> Result<()>;
+}
+
+/// Basic auth with the user ID and password of the [`MutualTls`]
+/// configuration.
+impl AuthenticationState for WithMutualTls {
+    fn authenticate(&self, request: &mut reqwest::Request, _base_url: &Url) -> Result<()> {
+        let credentials = Zeroizing::new(format!(
+            "{}:{}",
+            self.0.user_id,
+            self.0.password.expose_secret()
+        ));
+        let header = Zeroizing::new(format!("Basic {}", STANDARD.encode(credentials.as_bytes())));
+        let mut value = reqwest::header::HeaderValue::from_str(&header).map_err(Error::custom)?;
+        value.set_sensitive(true);
+        request
+            .headers_mut()
+            .insert(reqwest::header::AUTHORIZATION, value);
+        Ok(())
+    }
+}
+
+/// The `apikey` query parameter and the `x-pay-token` header.
+impl AuthenticationState for WithXPayToken {
+    fn authenticate(&self, request: &mut reqwest::Request, base_url: &Url) -> Result<()> {
+        super::x_pay_token::apply(&self.0, base_url, request)
+    }
+}
 // endregion:    --- Mutual TLS ---
 
 // region:    --- Message Level Encryption ---
@@ -57,7 +114,7 @@ impl MutualTlsState for WithMutualTls {}
 pub struct WithoutMessageLevelEncryption;
 
 /// Represents the state where Message Level Encryption is enabled.
-#[derive(Default, Clone)]
+#[derive(Clone)]
 pub struct WithMessageLevelEncryption(pub(crate) MessageLevelEncryption);
 
 /// Trait representing the state of Message Level Encryption.
@@ -68,6 +125,11 @@ pub trait MessageLevelEncryptionState {
     fn has_mle(&self) -> bool {
         false
     }
+
+    /// Returns the Message Level Encryption keys, if enabled.
+    fn message_level_encryption(&self) -> Option<&MessageLevelEncryption> {
+        None
+    }
 }
 
 /// Implementation of `MessageLevelEncryptionState` for
@@ -80,6 +142,10 @@ impl MessageLevelEncryptionState for WithMessageLevelEncryption {
     fn has_mle(&self) -> bool {
         true
     }
+
+    fn message_level_encryption(&self) -> Option<&MessageLevelEncryption> {
+        Some(&self.0)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,87 @@
+//! # TLS Stand-in
+//!
+//! A local HTTPS server standing in for the Visa API. Like Visa, it requires
+//! a client certificate, issued by the test CA in `testdata/ca_cert.pem`.
+
+use rustls::{
+    crypto::ring::default_provider,
+    pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer},
+    server::WebPkiClientVerifier,
+    RootCertStore, ServerConfig,
+};
+use std::sync::Arc;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+use tokio_rustls::TlsAcceptor;
+use url::Url;
+
+pub(crate) const TEST_CA_PEM: &str = include_str!("testdata/ca_cert.pem");
+const SERVER_CERT_PEM: &str = include_str!("testdata/server_cert.pem");
+const SERVER_KEY_PEM: &str = include_str!("testdata/server_key.pem");
+
+/// The body of every response of the stand-in.
+pub(crate) const RESPONSE_BODY: &str =
+    r#"{"timestamp": "2024-01-08T10:22:15", "message": "helloworld"}"#;
+
+/// Starts the stand-in on a random port, and returns its base URL. The server
+/// answers every request with a `200 OK` and [`RESPONSE_BODY`].
+pub(crate) async fn spawn_tls_server() -> Url {
+    let provider = Arc::new(default_provider());
+
+    let mut roots = RootCertStore::empty();
+    roots
+        .add(CertificateDer::from_pem_slice(TEST_CA_PEM.as_bytes()).unwrap())
+        .unwrap();
+    let client_verifier =
+        WebPkiClientVerifier::builder_with_provider(roots.into(), provider.clone())
+            .build()
+            .unwrap();
+
+    let config = ServerConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_client_cert_verifier(client_verifier)
+        .with_single_cert(
+            vec![CertificateDer::from_pem_slice(SERVER_CERT_PEM.as_bytes()).unwrap()],
+            PrivateKeyDer::from_pem_slice(SERVER_KEY_PEM.as_bytes()).unwrap(),
+        )
+        .unwrap();
+    let acceptor = TlsAcceptor::from(Arc::new(config));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let acceptor = acceptor.clone();
+            tokio::spawn(async move {
+                let Ok(mut stream) = acceptor.accept(stream).await else {
+                    return;
+                };
+
+                // Requests of the tests have no body, so the headers are all
+                // there is to read.
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    match stream.read(&mut buffer).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(read) => request.extend_from_slice(&buffer[..read]),
+                    }
+                }
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    RESPONSE_BODY.len(),
+                    RESPONSE_BODY
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+                let _ = stream.shutdown().await;
+            });
+        }
+    });
+
+    Url::parse(&format!("https://localhost:{port}/")).unwrap()
+}
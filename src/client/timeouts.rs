@@ -0,0 +1,30 @@
+//! # Timeouts
+//!
+//! Timeouts configured on the [`VisaClientBuilder`][super::VisaClientBuilder].
+//! Nothing times out unless configured.
+
+use std::time::Duration;
+
+/// Timeouts of the client.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Timeouts {
+    pub(crate) connect: Option<Duration>,
+    pub(crate) read: Option<Duration>,
+    pub(crate) total: Option<Duration>,
+}
+
+impl Timeouts {
+    /// Applies the timeouts to the reqwest client builder.
+    pub(crate) fn apply(&self, mut builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        if let Some(connect) = self.connect {
+            builder = builder.connect_timeout(connect);
+        }
+        if let Some(read) = self.read {
+            builder = builder.read_timeout(read);
+        }
+        if let Some(total) = self.total {
+            builder = builder.timeout(total);
+        }
+        builder
+    }
+}
@@ -1,9 +1,18 @@
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+compile_error!("either the `native-tls` or the `rustls` feature must be enabled");
+
 /// The main request client.
 pub mod client;
 
 /// API specific modules.
 pub mod api;
 
+/// Exact decimal money types.
+pub mod money;
+
+/// Synchronous facade over the client and the API modules.
+#[cfg(feature = "blocking")]
+pub mod blocking;
 
 mod utils;
 
@@ -0,0 +1,291 @@
+use core::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+use super::{Currency, Rate};
+
+/// A monetary amount, in major units of its currency.
+///
+/// Serialized as a JSON string (e.g. `"100.55"`) to keep the exact value.
+/// Deserialized from either a JSON string or a JSON number.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Amount(#[serde(deserialize_with = "super::deserialize_decimal")] Decimal);
+
+impl Amount {
+    /// An amount of zero.
+    pub const ZERO: Amount = Amount(Decimal::ZERO);
+
+    /// Creates an amount from a mantissa and a scale, e.g. `Amount::new(10055,
+    /// 2)` is `100.55`.
+    pub fn new(mantissa: i64, scale: u32) -> Self {
+        Amount(Decimal::new(mantissa, scale))
+    }
+
+    /// Creates an amount from minor units, given the minor-unit exponent of
+    /// the currency, e.g. `Amount::from_minor_units(10055, 2)` is `100.55`.
+    pub fn from_minor_units(minor_units: i64, exponent: u32) -> Self {
+        Amount::new(minor_units, exponent)
+    }
+
+    /// Returns the amount in minor units, given the minor-unit exponent of the
+    /// currency. Returns [None] if the amount has more decimal places than the
+    /// exponent allows (round it first with [`Amount::round_dp`]), or if it
+    /// does not fit in an `i64`.
+    pub fn to_minor_units(&self, exponent: u32) -> Option<i64> {
+        if !self.fits_exponent(exponent) {
+            return None;
+        }
+        let mut minor_units = self.0;
+        minor_units.rescale(exponent);
+        i64::try_from(minor_units.mantissa()).ok()
+    }
+
+    /// Returns `true` if the amount has no more decimal places than the
+    /// minor-unit exponent of the currency.
+    pub fn fits_exponent(&self, exponent: u32) -> bool {
+        self.0.normalize().scale() <= exponent
+    }
+
+    /// Rounds the amount to `decimal_places`, rounding half away from zero.
+    pub fn round_dp(&self, decimal_places: u32) -> Self {
+        Amount(
+            self.0
+                .round_dp_with_strategy(decimal_places, RoundingStrategy::MidpointAwayFromZero),
+        )
+    }
+
+    /// Rounds the amount to the minor units of `currency`, rounding half away
+    /// from zero.
+    pub fn round_for(&self, currency: Currency) -> Self {
+        self.round_dp(currency.minor_units())
+    }
+
+    /// Returns `true` if the amount has no more decimal places than the minor
+    /// units of `currency`.
+    pub fn fits_currency(&self, currency: Currency) -> bool {
+        self.fits_exponent(currency.minor_units())
+    }
+
+    /// Returns the amount in minor units of `currency`. See
+    /// [`Amount::to_minor_units`].
+    pub fn to_minor_units_for(&self, currency: Currency) -> Option<i64> {
+        self.to_minor_units(currency.minor_units())
+    }
+
+    /// Rounds the amount to `decimal_places` with the given strategy.
+    pub fn round_dp_with_strategy(&self, decimal_places: u32, strategy: RoundingStrategy) -> Self {
+        Amount(self.0.round_dp_with_strategy(decimal_places, strategy))
+    }
+
+    /// Converts the amount with the given rate. The result is not rounded.
+    pub fn convert(&self, rate: Rate) -> Self {
+        *self * rate
+    }
+
+    /// Checked addition. Returns [None] on overflow.
+    pub fn checked_add(&self, other: Amount) -> Option<Self> {
+        self.0.checked_add(other.0).map(Amount)
+    }
+
+    /// Checked subtraction. Returns [None] on overflow.
+    pub fn checked_sub(&self, other: Amount) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Amount)
+    }
+
+    /// Checked multiplication by a rate. Returns [None] on overflow.
+    pub fn checked_mul(&self, rate: Rate) -> Option<Self> {
+        self.0.checked_mul(rate.as_decimal()).map(Amount)
+    }
+
+    /// Returns `true` if the amount is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns `true` if the amount is negative.
+    pub fn is_sign_negative(&self) -> bool {
+        self.0.is_sign_negative() && !self.0.is_zero()
+    }
+
+    /// Returns the underlying decimal.
+    pub fn as_decimal(&self) -> Decimal {
+        self.0
+    }
+}
+
+impl From<Decimal> for Amount {
+    fn from(value: Decimal) -> Self {
+        Amount(value)
+    }
+}
+
+impl From<Amount> for Decimal {
+    fn from(value: Amount) -> Self {
+        value.0
+    }
+}
+
+impl From<i64> for Amount {
+    fn from(value: i64) -> Self {
+        Amount(Decimal::from(value))
+    }
+}
+
+impl FromStr for Amount {
+    type Err = rust_decimal::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Decimal::from_str_exact(value.trim()).map(Amount)
+    }
+}
+
+impl TryFrom<&str> for Amount {
+    type Error = rust_decimal::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+// region:    --- Arithmetic
+
+impl Add for Amount {
+    type Output = Amount;
+
+    fn add(self, other: Amount) -> Amount {
+        Amount(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Amount {
+    fn add_assign(&mut self, other: Amount) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Amount {
+    type Output = Amount;
+
+    fn sub(self, other: Amount) -> Amount {
+        Amount(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Amount {
+    fn sub_assign(&mut self, other: Amount) {
+        self.0 -= other.0;
+    }
+}
+
+impl Neg for Amount {
+    type Output = Amount;
+
+    fn neg(self) -> Amount {
+        Amount(-self.0)
+    }
+}
+
+impl Mul<Rate> for Amount {
+    type Output = Amount;
+
+    fn mul(self, rate: Rate) -> Amount {
+        Amount(self.0 * rate.as_decimal())
+    }
+}
+
+impl Sum for Amount {
+    fn sum<I: Iterator<Item = Amount>>(iter: I) -> Self {
+        iter.fold(Amount::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Amount> for Amount {
+    fn sum<I: Iterator<Item = &'a Amount>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+// endregion: --- Arithmetic
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amount_deserializes_string_and_number_exactly() {
+        let from_string: Amount = serde_json::from_str(r#""100.55""#).unwrap();
+        let from_number: Amount = serde_json::from_str("100.55").unwrap();
+        let from_integer: Amount = serde_json::from_str("100").unwrap();
+        let precise: Amount = serde_json::from_str("9876543210.12345").unwrap();
+
+        assert_eq!(from_string, Amount::new(10055, 2));
+        assert_eq!(from_number, Amount::new(10055, 2));
+        assert_eq!(from_integer, Amount::new(100, 0));
+        assert_eq!(precise.to_string(), "9876543210.12345");
+    }
+
+    #[cfg(feature = "arbitrary-precision")]
+    #[test]
+    fn test_amount_deserializes_number_beyond_f64() {
+        // Not representable as an f64.
+        let precise: Amount = serde_json::from_str("1234567890123456.78").unwrap();
+        assert_eq!(precise.to_string(), "1234567890123456.78");
+    }
+
+    #[test]
+    fn test_amount_serializes_as_string() {
+        assert_eq!(
+            serde_json::to_string(&Amount::new(10055, 2)).unwrap(),
+            r#""100.55""#
+        );
+    }
+
+    #[test]
+    fn test_amount_minor_units() {
+        assert_eq!(Amount::new(10055, 2).to_minor_units(2), Some(10055));
+        assert_eq!(Amount::new(100, 0).to_minor_units(2), Some(10000));
+        assert_eq!(Amount::new(10055, 2).to_minor_units(0), None);
+        assert_eq!(Amount::new(100500, 3).to_minor_units(2), Some(10050));
+        assert_eq!(Amount::from_minor_units(1234, 3).to_string(), "1.234");
+        assert_eq!(
+            Amount::new(10055, 2).to_minor_units_for(Currency::JPY),
+            None
+        );
+        assert_eq!(
+            Amount::new(10055, 2).round_for(Currency::JPY),
+            Amount::from(101)
+        );
+        assert!(Amount::new(1005, 3).fits_currency(Currency::KWD));
+        assert!(!Amount::new(1005, 3).fits_currency(Currency::USD));
+    }
+
+    #[test]
+    fn test_amount_arithmetic() {
+        let amount = Amount::new(10055, 2);
+        let rate: Rate = "0.754".parse().unwrap();
+
+        assert_eq!(amount + Amount::new(45, 2), Amount::from(101));
+        assert_eq!(amount - Amount::new(55, 2), Amount::from(100));
+        assert_eq!((amount * rate).round_dp(2), Amount::new(7581, 2));
+        assert_eq!(Amount::new(5, 3).round_dp(2), Amount::new(1, 2));
+        assert_eq!(
+            [amount, amount].iter().sum::<Amount>(),
+            Amount::new(20110, 2)
+        );
+    }
+}
@@ -0,0 +1,480 @@
+use core::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::utils::{BuilderError, BuilderResult};
+
+/// An ISO 4217 currency.
+///
+/// Backed by an embedded ISO 4217 table. Each currency is available in its
+/// alpha-3 form (e.g. `"USD"`), its numeric form (e.g. `840`), and carries its
+/// minor-unit exponent (e.g. `2` for cents).
+///
+/// Serialized in its alpha-3 form. Deserialized from either form, as a string
+/// or a number. Use [`currency_numeric`][super::currency_numeric] for the
+/// fields that Visa expects in the numeric form.
+///
+/// ## Example
+///
+/// ```
+/// use visa_sdk::money::Currency;
+///
+/// let usd: Currency = "USD".parse().unwrap();
+/// assert_eq!(usd, Currency::USD);
+/// assert_eq!(usd.numeric_code(), 840);
+/// assert_eq!(usd.minor_units(), 2);
+/// assert_eq!("840".parse::<Currency>().unwrap(), Currency::USD);
+/// assert!("USX".parse::<Currency>().is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency {
+    alpha_code: &'static str,
+    numeric_code: u16,
+    minor_units: u32,
+    name: &'static str,
+}
+
+macro_rules! iso4217 {
+    ($($ident:ident, $alpha:literal, $numeric:literal, $minor:literal, $name:literal;)*) => {
+        impl Currency {
+            $(
+                #[doc = concat!($name, " (`", $alpha, "`).")]
+                pub const $ident: Currency = Currency {
+                    alpha_code: $alpha,
+                    numeric_code: $numeric,
+                    minor_units: $minor,
+                    name: $name,
+                };
+            )*
+        }
+
+        /// All the currencies of the embedded ISO 4217 table.
+        const CURRENCIES: &[Currency] = &[$(Currency::$ident),*];
+    };
+}
+
+iso4217! {
+    AED, "AED", 784, 2, "UAE Dirham";
+    AFN, "AFN", 971, 2, "Afghani";
+    ALL, "ALL", 8, 2, "Lek";
+    AMD, "AMD", 51, 2, "Armenian Dram";
+    ANG, "ANG", 532, 2, "Netherlands Antillean Guilder";
+    AOA, "AOA", 973, 2, "Kwanza";
+    ARS, "ARS", 32, 2, "Argentine Peso";
+    AUD, "AUD", 36, 2, "Australian Dollar";
+    AWG, "AWG", 533, 2, "Aruban Florin";
+    AZN, "AZN", 944, 2, "Azerbaijan Manat";
+    BAM, "BAM", 977, 2, "Convertible Mark";
+    BBD, "BBD", 52, 2, "Barbados Dollar";
+    BDT, "BDT", 50, 2, "Taka";
+    BGN, "BGN", 975, 2, "Bulgarian Lev";
+    BHD, "BHD", 48, 3, "Bahraini Dinar";
+    BIF, "BIF", 108, 0, "Burundi Franc";
+    BMD, "BMD", 60, 2, "Bermudian Dollar";
+    BND, "BND", 96, 2, "Brunei Dollar";
+    BOB, "BOB", 68, 2, "Boliviano";
+    BRL, "BRL", 986, 2, "Brazilian Real";
+    BSD, "BSD", 44, 2, "Bahamian Dollar";
+    BTN, "BTN", 64, 2, "Ngultrum";
+    BWP, "BWP", 72, 2, "Pula";
+    BYN, "BYN", 933, 2, "Belarusian Ruble";
+    BZD, "BZD", 84, 2, "Belize Dollar";
+    CAD, "CAD", 124, 2, "Canadian Dollar";
+    CDF, "CDF", 976, 2, "Congolese Franc";
+    CHF, "CHF", 756, 2, "Swiss Franc";
+    CLF, "CLF", 990, 4, "Unidad de Fomento";
+    CLP, "CLP", 152, 0, "Chilean Peso";
+    CNY, "CNY", 156, 2, "Yuan Renminbi";
+    COP, "COP", 170, 2, "Colombian Peso";
+    CRC, "CRC", 188, 2, "Costa Rican Colon";
+    CUP, "CUP", 192, 2, "Cuban Peso";
+    CVE, "CVE", 132, 2, "Cabo Verde Escudo";
+    CZK, "CZK", 203, 2, "Czech Koruna";
+    DJF, "DJF", 262, 0, "Djibouti Franc";
+    DKK, "DKK", 208, 2, "Danish Krone";
+    DOP, "DOP", 214, 2, "Dominican Peso";
+    DZD, "DZD", 12, 2, "Algerian Dinar";
+    EGP, "EGP", 818, 2, "Egyptian Pound";
+    ERN, "ERN", 232, 2, "Nakfa";
+    ETB, "ETB", 230, 2, "Ethiopian Birr";
+    EUR, "EUR", 978, 2, "Euro";
+    FJD, "FJD", 242, 2, "Fiji Dollar";
+    FKP, "FKP", 238, 2, "Falkland Islands Pound";
+    GBP, "GBP", 826, 2, "Pound Sterling";
+    GEL, "GEL", 981, 2, "Lari";
+    GHS, "GHS", 936, 2, "Ghana Cedi";
+    GIP, "GIP", 292, 2, "Gibraltar Pound";
+    GMD, "GMD", 270, 2, "Dalasi";
+    GNF, "GNF", 324, 0, "Guinean Franc";
+    GTQ, "GTQ", 320, 2, "Quetzal";
+    GYD, "GYD", 328, 2, "Guyana Dollar";
+    HKD, "HKD", 344, 2, "Hong Kong Dollar";
+    HNL, "HNL", 340, 2, "Lempira";
+    HTG, "HTG", 332, 2, "Gourde";
+    HUF, "HUF", 348, 2, "Forint";
+    IDR, "IDR", 360, 2, "Rupiah";
+    ILS, "ILS", 376, 2, "New Israeli Sheqel";
+    INR, "INR", 356, 2, "Indian Rupee";
+    IQD, "IQD", 368, 3, "Iraqi Dinar";
+    IRR, "IRR", 364, 2, "Iranian Rial";
+    ISK, "ISK", 352, 0, "Iceland Krona";
+    JMD, "JMD", 388, 2, "Jamaican Dollar";
+    JOD, "JOD", 400, 3, "Jordanian Dinar";
+    JPY, "JPY", 392, 0, "Yen";
+    KES, "KES", 404, 2, "Kenyan Shilling";
+    KGS, "KGS", 417, 2, "Som";
+    KHR, "KHR", 116, 2, "Riel";
+    KMF, "KMF", 174, 0, "Comorian Franc";
+    KPW, "KPW", 408, 2, "North Korean Won";
+    KRW, "KRW", 410, 0, "Won";
+    KWD, "KWD", 414, 3, "Kuwaiti Dinar";
+    KYD, "KYD", 136, 2, "Cayman Islands Dollar";
+    KZT, "KZT", 398, 2, "Tenge";
+    LAK, "LAK", 418, 2, "Lao Kip";
+    LBP, "LBP", 422, 2, "Lebanese Pound";
+    LKR, "LKR", 144, 2, "Sri Lanka Rupee";
+    LRD, "LRD", 430, 2, "Liberian Dollar";
+    LSL, "LSL", 426, 2, "Loti";
+    LYD, "LYD", 434, 3, "Libyan Dinar";
+    MAD, "MAD", 504, 2, "Moroccan Dirham";
+    MDL, "MDL", 498, 2, "Moldovan Leu";
+    MGA, "MGA", 969, 2, "Malagasy Ariary";
+    MKD, "MKD", 807, 2, "Denar";
+    MMK, "MMK", 104, 2, "Kyat";
+    MNT, "MNT", 496, 2, "Tugrik";
+    MOP, "MOP", 446, 2, "Pataca";
+    MRU, "MRU", 929, 2, "Ouguiya";
+    MUR, "MUR", 480, 2, "Mauritius Rupee";
+    MVR, "MVR", 462, 2, "Rufiyaa";
+    MWK, "MWK", 454, 2, "Malawi Kwacha";
+    MXN, "MXN", 484, 2, "Mexican Peso";
+    MYR, "MYR", 458, 2, "Malaysian Ringgit";
+    MZN, "MZN", 943, 2, "Mozambique Metical";
+    NAD, "NAD", 516, 2, "Namibia Dollar";
+    NGN, "NGN", 566, 2, "Naira";
+    NIO, "NIO", 558, 2, "Cordoba Oro";
+    NOK, "NOK", 578, 2, "Norwegian Krone";
+    NPR, "NPR", 524, 2, "Nepalese Rupee";
+    NZD, "NZD", 554, 2, "New Zealand Dollar";
+    OMR, "OMR", 512, 3, "Rial Omani";
+    PAB, "PAB", 590, 2, "Balboa";
+    PEN, "PEN", 604, 2, "Sol";
+    PGK, "PGK", 598, 2, "Kina";
+    PHP, "PHP", 608, 2, "Philippine Peso";
+    PKR, "PKR", 586, 2, "Pakistan Rupee";
+    PLN, "PLN", 985, 2, "Zloty";
+    PYG, "PYG", 600, 0, "Guarani";
+    QAR, "QAR", 634, 2, "Qatari Rial";
+    RON, "RON", 946, 2, "Romanian Leu";
+    RSD, "RSD", 941, 2, "Serbian Dinar";
+    RUB, "RUB", 643, 2, "Russian Ruble";
+    RWF, "RWF", 646, 0, "Rwanda Franc";
+    SAR, "SAR", 682, 2, "Saudi Riyal";
+    SBD, "SBD", 90, 2, "Solomon Islands Dollar";
+    SCR, "SCR", 690, 2, "Seychelles Rupee";
+    SDG, "SDG", 938, 2, "Sudanese Pound";
+    SEK, "SEK", 752, 2, "Swedish Krona";
+    SGD, "SGD", 702, 2, "Singapore Dollar";
+    SHP, "SHP", 654, 2, "Saint Helena Pound";
+    SLE, "SLE", 925, 2, "Leone";
+    SOS, "SOS", 706, 2, "Somali Shilling";
+    SRD, "SRD", 968, 2, "Surinam Dollar";
+    SSP, "SSP", 728, 2, "South Sudanese Pound";
+    STN, "STN", 930, 2, "Dobra";
+    SVC, "SVC", 222, 2, "El Salvador Colon";
+    SYP, "SYP", 760, 2, "Syrian Pound";
+    SZL, "SZL", 748, 2, "Lilangeni";
+    THB, "THB", 764, 2, "Baht";
+    TJS, "TJS", 972, 2, "Somoni";
+    TMT, "TMT", 934, 2, "Turkmenistan New Manat";
+    TND, "TND", 788, 3, "Tunisian Dinar";
+    TOP, "TOP", 776, 2, "Pa'anga";
+    TRY, "TRY", 949, 2, "Turkish Lira";
+    TTD, "TTD", 780, 2, "Trinidad and Tobago Dollar";
+    TWD, "TWD", 901, 2, "New Taiwan Dollar";
+    TZS, "TZS", 834, 2, "Tanzanian Shilling";
+    UAH, "UAH", 980, 2, "Hryvnia";
+    UGX, "UGX", 800, 0, "Uganda Shilling";
+    USD, "USD", 840, 2, "US Dollar";
+    UYI, "UYI", 940, 0, "Uruguay Peso en Unidades Indexadas";
+    UYU, "UYU", 858, 2, "Peso Uruguayo";
+    UYW, "UYW", 927, 4, "Unidad Previsional";
+    UZS, "UZS", 860, 2, "Uzbekistan Sum";
+    VED, "VED", 926, 2, "Bolivar Soberano";
+    VES, "VES", 928, 2, "Bolivar Soberano";
+    VND, "VND", 704, 0, "Dong";
+    VUV, "VUV", 548, 0, "Vatu";
+    WST, "WST", 882, 2, "Tala";
+    XAF, "XAF", 950, 0, "CFA Franc BEAC";
+    XCD, "XCD", 951, 2, "East Caribbean Dollar";
+    XOF, "XOF", 952, 0, "CFA Franc BCEAO";
+    XPF, "XPF", 953, 0, "CFP Franc";
+    YER, "YER", 886, 2, "Yemeni Rial";
+    ZAR, "ZAR", 710, 2, "Rand";
+    ZMW, "ZMW", 967, 2, "Zambian Kwacha";
+    ZWG, "ZWG", 924, 2, "Zimbabwe Gold";
+}
+
+impl Currency {
+    /// Returns all the currencies of the embedded ISO 4217 table.
+    pub fn all() -> &'static [Currency] {
+        CURRENCIES
+    }
+
+    /// Finds a currency by its alpha-3 code, case-insensitively.
+    pub fn from_alpha_code(alpha_code: &str) -> Option<Currency> {
+        CURRENCIES
+            .iter()
+            .find(|currency| currency.alpha_code.eq_ignore_ascii_case(alpha_code))
+            .copied()
+    }
+
+    /// Finds a currency by its numeric code.
+    pub fn from_numeric_code(numeric_code: u16) -> Option<Currency> {
+        CURRENCIES
+            .iter()
+            .find(|currency| currency.numeric_code == numeric_code)
+            .copied()
+    }
+
+    /// The alpha-3 code, e.g. `"USD"`.
+    pub fn alpha_code(&self) -> &'static str {
+        self.alpha_code
+    }
+
+    /// The numeric code, e.g. `840`.
+    pub fn numeric_code(&self) -> u16 {
+        self.numeric_code
+    }
+
+    /// The numeric code as a zero-padded 3-digit string, e.g. `"008"`.
+    pub fn numeric_code_str(&self) -> String {
+        format!("{:03}", self.numeric_code)
+    }
+
+    /// The minor-unit exponent, e.g. `2` for USD and `0` for JPY.
+    pub fn minor_units(&self) -> u32 {
+        self.minor_units
+    }
+
+    /// The English name of the currency.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Error returned when a currency code is not part of the ISO 4217 table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCurrency(pub String);
+
+impl fmt::Display for UnknownCurrency {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "unknown ISO 4217 currency code: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownCurrency {}
+
+impl FromStr for Currency {
+    type Err = UnknownCurrency;
+
+    /// Parses either the alpha-3 (`"USD"`) or the numeric (`"840"`) form.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let currency = if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+            value.parse().ok().and_then(Currency::from_numeric_code)
+        } else {
+            Currency::from_alpha_code(value)
+        };
+        currency.ok_or_else(|| UnknownCurrency(value.to_string()))
+    }
+}
+
+impl TryFrom<&str> for Currency {
+    type Error = UnknownCurrency;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(self.alpha_code)
+    }
+}
+
+impl fmt::Debug for Currency {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Currency({})", self.alpha_code)
+    }
+}
+
+// region:    --- Serde
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.alpha_code)
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(CurrencyVisitor)
+    }
+}
+
+struct CurrencyVisitor;
+
+impl<'de> de::Visitor<'de> for CurrencyVisitor {
+    type Value = Currency;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an ISO 4217 alpha-3 or numeric currency code")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Currency, E> {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Currency, E> {
+        u16::try_from(value)
+            .ok()
+            .and_then(Currency::from_numeric_code)
+            .ok_or_else(|| E::custom(UnknownCurrency(value.to_string())))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Currency, E> {
+        u64::try_from(value)
+            .map_err(|_| E::custom(UnknownCurrency(value.to_string())))
+            .and_then(|value| self.visit_u64(value))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Currency, A::Error> {
+        // JSON numbers are deserialized as maps with serde_json's
+        // `arbitrary_precision` feature.
+        let number = serde_json::Number::deserialize(de::value::MapAccessDeserializer::new(map))?;
+        self.visit_str(&number.to_string())
+    }
+}
+
+/// Serializes a [`Currency`] in its numeric form (e.g. `"840"`), for use with
+/// `#[serde(with = "crate::money::currency_numeric")]`. Deserializes either
+/// form.
+pub mod currency_numeric {
+    use super::Currency;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(currency: &Currency, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&currency.numeric_code_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Currency, D::Error> {
+        Currency::deserialize(deserializer)
+    }
+}
+
+// endregion: --- Serde
+
+// region:    --- Builder
+
+/// The builder input of a [`Currency`] field. Accepts a [`Currency`], or a
+/// code in either form which is validated when the request is built.
+#[derive(Clone, Debug, Default)]
+pub enum CurrencyInput {
+    /// No currency was given.
+    #[default]
+    Unset,
+    /// An alpha-3 or numeric code, not validated yet.
+    Code(String),
+    /// A known currency.
+    Currency(Currency),
+}
+
+impl CurrencyInput {
+    /// Resolves the input into a [`Currency`]. Unknown codes fail with
+    /// [`BuilderError::ValidationViolition`].
+    pub(crate) fn build(&self, field_name: &'static str) -> BuilderResult<Currency> {
+        match self {
+            CurrencyInput::Unset => {
+                Err(derive_builder::UninitializedFieldError::new(field_name).into())
+            }
+            CurrencyInput::Code(code) => code
+                .parse()
+                .map_err(|e| BuilderError::ValidationViolition(format!("{field_name}: {e}"))),
+            CurrencyInput::Currency(currency) => Ok(*currency),
+        }
+    }
+}
+
+impl From<Currency> for CurrencyInput {
+    fn from(value: Currency) -> Self {
+        CurrencyInput::Currency(value)
+    }
+}
+
+impl From<&str> for CurrencyInput {
+    fn from(value: &str) -> Self {
+        CurrencyInput::Code(value.to_string())
+    }
+}
+
+impl From<String> for CurrencyInput {
+    fn from(value: String) -> Self {
+        CurrencyInput::Code(value)
+    }
+}
+
+// endregion: --- Builder
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_table_is_consistent() {
+        for (i, currency) in CURRENCIES.iter().enumerate() {
+            assert_eq!(currency.alpha_code.len(), 3);
+            assert!(currency.numeric_code < 1000);
+            assert!(CURRENCIES[i + 1..]
+                .iter()
+                .all(|other| other.alpha_code != currency.alpha_code
+                    && other.numeric_code != currency.numeric_code));
+        }
+    }
+
+    #[test]
+    fn test_currency_conversions() {
+        assert_eq!(Currency::from_alpha_code("gbp"), Some(Currency::GBP));
+        assert_eq!(Currency::from_numeric_code(392), Some(Currency::JPY));
+        assert_eq!(Currency::ALL.numeric_code_str(), "008");
+        assert_eq!(Currency::JPY.minor_units(), 0);
+        assert_eq!(Currency::KWD.minor_units(), 3);
+        assert_eq!("008".parse::<Currency>(), Ok(Currency::ALL));
+        assert!("999".parse::<Currency>().is_err());
+        assert!("US".parse::<Currency>().is_err());
+    }
+
+    #[test]
+    fn test_currency_serde() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Payload {
+            alpha: Currency,
+            #[serde(with = "currency_numeric")]
+            numeric: Currency,
+        }
+
+        let payload = Payload {
+            alpha: Currency::USD,
+            numeric: Currency::USD,
+        };
+        let json = serde_json::json!({ "alpha": "USD", "numeric": "840" });
+        assert_eq!(serde_json::to_value(&payload).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Payload>(json).unwrap(), payload);
+
+        let from_number: Payload =
+            serde_json::from_str(r#"{ "alpha": 826, "numeric": 840 }"#).unwrap();
+        assert_eq!(from_number.alpha, Currency::GBP);
+        assert!(serde_json::from_str::<Currency>(r#""XYZ""#).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+//! # Money Module
+//!
+//! Exact decimal types for the monetary values exchanged with the Visa APIs.
+//! Amounts and rates are backed by [`rust_decimal::Decimal`], and accept the
+//! string (`"100.55"`) as well as the number (`100.55`) JSON forms. JSON
+//! numbers are read exactly up to 15 significant digits, or at any precision
+//! with the `arbitrary-precision` feature.
+//!
+//! - [`Amount`][crate::money::Amount]: A monetary amount, in major units of its currency (e.g.
+//!   `100.55` USD). Can be rounded to, and converted from/to, the minor units
+//!   of a currency given its exponent.
+//! - [`Rate`][crate::money::Rate]: A conversion or markup rate.
+//! - [`Currency`][crate::money::Currency]: An ISO 4217 currency, convertible between its alpha-3 and
+//!   numeric forms, with its minor-unit exponent.
+//!
+//! ## Example
+//!
+//! ```
+//! use visa_sdk::money::{Amount, Rate};
+//!
+//! let amount: Amount = "100.55".parse().unwrap();
+//! let rate: Rate = "0.754".parse().unwrap();
+//!
+//! let converted = amount * rate;
+//! assert_eq!(converted.to_string(), "75.81470");
+//! assert_eq!(converted.round_dp(2).to_string(), "75.81");
+//! assert_eq!(converted.round_dp(2).to_minor_units(2), Some(7581));
+//! ```
+
+mod amount;
+mod currency;
+mod rate;
+
+pub use amount::*;
+pub use currency::*;
+pub use rate::*;
+
+/// The underlying decimal type of [`Amount`] and [`Rate`].
+pub use rust_decimal::Decimal;
+
+use core::fmt;
+use serde::{de, Deserialize, Deserializer};
+
+// region:    --- Serde
+
+/// Deserializes a [`Decimal`] from a JSON string or number.
+///
+/// A JSON number is handed over as an `f64`, unless serde_json's
+/// `arbitrary_precision` is enabled, and is read from its shortest
+/// representation, which is exact up to 15 significant digits.
+fn deserialize_decimal<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Decimal, D::Error> {
+    deserializer.deserialize_any(DecimalVisitor)
+}
+
+struct DecimalVisitor;
+
+impl<'de> de::Visitor<'de> for DecimalVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a decimal number, or a string of one")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Decimal, E> {
+        Decimal::from_str_exact(value.trim()).map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Decimal, E> {
+        Ok(Decimal::from(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Decimal, E> {
+        Ok(Decimal::from(value))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Decimal, E> {
+        if !value.is_finite() {
+            return Err(E::custom(format!("{value} is not a decimal number")));
+        }
+        self.visit_str(&value.to_string())
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Decimal, A::Error> {
+        // JSON numbers are deserialized as maps with serde_json's
+        // `arbitrary_precision` feature.
+        let number = serde_json::Number::deserialize(de::value::MapAccessDeserializer::new(map))?;
+        self.visit_str(&number.to_string())
+    }
+}
+
+// endregion: --- Serde
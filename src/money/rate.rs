@@ -0,0 +1,82 @@
+use core::{fmt, str::FromStr};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::Amount;
+
+/// A conversion rate, or a markup rate expressed as a percentage.
+///
+/// Serialized as a JSON string (e.g. `"0.754"`) to keep the exact value.
+/// Deserialized from either a JSON string or a JSON number.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Rate(#[serde(deserialize_with = "super::deserialize_decimal")] Decimal);
+
+impl Rate {
+    /// A rate of one.
+    pub const ONE: Rate = Rate(Decimal::ONE);
+
+    /// Creates a rate from a mantissa and a scale, e.g. `Rate::new(754, 3)` is
+    /// `0.754`.
+    pub fn new(mantissa: i64, scale: u32) -> Self {
+        Rate(Decimal::new(mantissa, scale))
+    }
+
+    /// Applies the rate on an amount. The result is not rounded.
+    pub fn apply(&self, amount: Amount) -> Amount {
+        amount * *self
+    }
+
+    /// Returns the inverse rate, or [None] if the rate is zero.
+    pub fn inverse(&self) -> Option<Rate> {
+        Decimal::ONE.checked_div(self.0).map(Rate)
+    }
+
+    /// Returns the rate after applying a markup expressed as a percentage,
+    /// e.g. a markup of `0.07` means 0.07%.
+    pub fn with_markup_percentage(&self, markup: Rate) -> Rate {
+        Rate(self.0 * (Decimal::ONE_HUNDRED + markup.0) / Decimal::ONE_HUNDRED)
+    }
+
+    /// Returns the underlying decimal.
+    pub fn as_decimal(&self) -> Decimal {
+        self.0
+    }
+}
+
+impl From<Decimal> for Rate {
+    fn from(value: Decimal) -> Self {
+        Rate(value)
+    }
+}
+
+impl From<Rate> for Decimal {
+    fn from(value: Rate) -> Self {
+        value.0
+    }
+}
+
+impl FromStr for Rate {
+    type Err = rust_decimal::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Decimal::from_str_exact(value.trim()).map(Rate)
+    }
+}
+
+impl TryFrom<&str> for Rate {
+    type Error = rust_decimal::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for Rate {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
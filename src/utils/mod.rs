@@ -1,3 +1,6 @@
 mod builder_result;
+mod secret;
+pub(crate) mod trace;
 
 pub use builder_result::*;
+pub use secret::*;
@@ -0,0 +1,65 @@
+use zeroize::{Zeroize, Zeroizing};
+
+/// A secret value, such as a password or a private key.
+///
+/// The value is zeroized when dropped, and never printed by [`Debug`]. It can
+/// only be read within the crate.
+#[derive(Clone, Default)]
+pub struct Secret<T: Zeroize>(Zeroizing<T>);
+
+impl<T: Zeroize> Secret<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Secret(Zeroizing::new(value))
+    }
+
+    /// The secret value. Keep the borrow short, and do not copy the value
+    /// out of a zeroizing container.
+    pub(crate) fn expose_secret(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Zeroize> std::fmt::Debug for Secret<T> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.write_str("[REDACTED]")
+    }
+}
+
+impl<'de, T> serde::Deserialize<'de> for Secret<T>
+where
+    T: Zeroize + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Secret::new)
+    }
+}
+
+impl From<String> for Secret<String> {
+    fn from(value: String) -> Self {
+        Secret::new(value)
+    }
+}
+
+impl From<&str> for Secret<String> {
+    fn from(value: &str) -> Self {
+        Secret::new(value.to_string())
+    }
+}
+
+impl From<Vec<u8>> for Secret<Vec<u8>> {
+    fn from(value: Vec<u8>) -> Self {
+        Secret::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_debug() {
+        let secret = Secret::from("super-secret-password");
+        assert_eq!(format!("{secret:?}"), "[REDACTED]");
+        assert_eq!(secret.expose_secret(), "super-secret-password");
+    }
+}
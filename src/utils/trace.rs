@@ -0,0 +1,136 @@
+//! # Tracing
+//!
+//! Helpers for the optional `tracing` instrumentation. Spans only record an
+//! explicit list of fields: the HTTP method, the path, the API level, the
+//! status, the latency, the retries and the correlation ID. Headers, query
+//! strings and bodies are never recorded, as they carry credentials, PANs and
+//! MLE payloads.
+
+/// Records `value` in `field` of the current span. A no-op without the
+/// `tracing` feature.
+macro_rules! record {
+    ($field:literal, $value:expr) => {
+        #[cfg(feature = "tracing")]
+        {
+            tracing::Span::current().record($field, $value);
+        }
+    };
+}
+
+pub(crate) use record;
+
+/// The path of `url`, without the query string, and with any segment looking
+/// like a PAN (13 to 19 digits) masked.
+#[cfg(feature = "tracing")]
+pub(crate) fn redacted_path(url: &url::Url) -> String {
+    url.path()
+        .split('/')
+        .map(|segment| {
+            let is_pan = (13..=19).contains(&segment.len())
+                && segment.bytes().all(|byte| byte.is_ascii_digit());
+            if is_pan {
+                "[REDACTED]"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(all(test, feature = "tracing"))]
+pub(crate) mod tests {
+    use super::*;
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    /// A subscriber keeping every recorded field as `name=value`. Meant for a
+    /// single-threaded test.
+    #[derive(Clone, Default)]
+    pub(crate) struct CapturingSubscriber {
+        pub(crate) fields: Arc<Mutex<Vec<String>>>,
+        spans: Arc<Mutex<HashMap<u64, &'static Metadata<'static>>>>,
+        entered: Arc<Mutex<Vec<span::Id>>>,
+    }
+
+    impl Visit for CapturingSubscriber {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.fields
+                .lock()
+                .unwrap()
+                .push(format!("{}={value:?}", field.name()));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.fields
+                .lock()
+                .unwrap()
+                .push(format!("{}={value}", field.name()));
+        }
+    }
+
+    impl Subscriber for CapturingSubscriber {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+            self.fields
+                .lock()
+                .unwrap()
+                .push(format!("span={}", span.metadata().name()));
+            span.record(&mut self.clone());
+
+            let mut spans = self.spans.lock().unwrap();
+            let id = spans.len() as u64 + 1;
+            spans.insert(id, span.metadata());
+            span::Id::from_u64(id)
+        }
+
+        fn record(&self, _: &span::Id, values: &span::Record<'_>) {
+            values.record(&mut self.clone());
+        }
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut self.clone());
+        }
+
+        fn enter(&self, id: &span::Id) {
+            self.entered.lock().unwrap().push(id.clone());
+        }
+
+        fn exit(&self, _: &span::Id) {
+            self.entered.lock().unwrap().pop();
+        }
+
+        fn current_span(&self) -> tracing_core::span::Current {
+            match self.entered.lock().unwrap().last() {
+                Some(id) => tracing_core::span::Current::new(
+                    id.clone(),
+                    self.spans.lock().unwrap()[&id.into_u64()],
+                ),
+                None => tracing_core::span::Current::none(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_redacted_path() {
+        let url = url::Url::parse(
+            "https://sandbox.api.visa.com/cards/4111111111111111/status?apikey=secret",
+        )
+        .unwrap();
+        assert_eq!(redacted_path(&url), "/cards/[REDACTED]/status");
+
+        let url = url::Url::parse("https://sandbox.api.visa.com/vdp/helloworld").unwrap();
+        assert_eq!(redacted_path(&url), "/vdp/helloworld");
+    }
+}